@@ -1,14 +1,129 @@
 //! Benchmark command implementation
+//!
+//! Times every `FibMethod` at a single n using the same adaptive-sampling/
+//! bootstrap-CI machinery as `fib-profiler`, and optionally persists or
+//! compares the result against a named baseline file under `results/`
+//! (mirroring `sweep`'s JSON-persistence convention), so a regression in one
+//! run can be flagged against a previous one the way Criterion does.
 
-pub fn run(filter: Option<String>) {
-    println!("📊 Running Criterion Benchmarks...");
+use fib_core::FibMethod;
+use fib_profiler::stats;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Algorithms that are only practical for small n (avoid O(2^n) blowups)
+const MAX_RECURSIVE_N: u64 = 30;
+
+/// One method's timing at a given n, either freshly measured or loaded back
+/// from a saved baseline file.
+struct MethodTiming {
+    method: String,
+    n: u64,
+    point_estimate_ns: f64,
+    lower_ns: f64,
+    upper_ns: f64,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn run(
+    filter: Option<String>,
+    warm_up_ms: u64,
+    measure_ms: u64,
+    min_samples: usize,
+    n: u64,
+    baseline: Option<String>,
+    compare: bool,
+    output_dir: &str,
+) {
+    println!("📊 Running Benchmarks");
+    println!("=====================");
     println!();
 
     if let Some(ref f) = filter {
         println!("Filter: {}", f);
     }
 
-    println!("To run full benchmarks, use:");
+    let methods = [
+        FibMethod::Recursive,
+        FibMethod::RecursiveMemo,
+        FibMethod::RecursiveAcc,
+        FibMethod::Iterative,
+        FibMethod::IterativeBranchless,
+        FibMethod::Matrix,
+        FibMethod::FastDoubling,
+        FibMethod::Binet,
+    ];
+
+    let warm_up = Duration::from_millis(warm_up_ms);
+    let measure_time = Duration::from_millis(measure_ms);
+
+    println!(
+        "Timing each method at n={} (warm-up {}ms, target {}ms, min {} samples):",
+        n, warm_up_ms, measure_ms, min_samples
+    );
+    println!();
+
+    let mut timings: Vec<MethodTiming> = Vec::new();
+    for method in methods {
+        if method == FibMethod::Recursive && n > MAX_RECURSIVE_N {
+            continue;
+        }
+        if let Some(ref f) = filter {
+            if !method.name().to_lowercase().contains(&f.to_lowercase()) {
+                continue;
+            }
+        }
+
+        let report = stats::profile_adaptive(
+            warm_up,
+            measure_time,
+            min_samples,
+            stats::DEFAULT_BOOTSTRAP_RESAMPLES,
+            || stats::black_box_call(n, |n| method.calculate(n)),
+        );
+
+        println!(
+            "  {:<18}: {:>10.1} ns  (95% CI [{:.1}, {:.1}])",
+            method.name(),
+            report.ci.point_estimate_ns,
+            report.ci.lower_ns,
+            report.ci.upper_ns
+        );
+
+        timings.push(MethodTiming {
+            method: method.name().to_string(),
+            n,
+            point_estimate_ns: report.ci.point_estimate_ns,
+            lower_ns: report.ci.lower_ns,
+            upper_ns: report.ci.upper_ns,
+        });
+    }
+
+    if let Some(name) = &baseline {
+        let path = baseline_path(output_dir, name);
+
+        if compare {
+            match load_baseline(&path) {
+                Ok(old_timings) => print_comparison(&timings, &old_timings),
+                Err(e) => eprintln!(
+                    "⚠️  Could not load baseline '{}' ({}): {}",
+                    name,
+                    path.display(),
+                    e
+                ),
+            }
+        }
+
+        match write_baseline(&path, &timings) {
+            Ok(()) => println!("\n💾 Saved baseline '{}' to {}", name, path.display()),
+            Err(e) => eprintln!("❌ Error saving baseline '{}': {}", name, e),
+        }
+    }
+
+    println!();
+    println!("To run full Criterion benchmarks, use:");
     println!();
     println!("  cargo bench");
     println!();
@@ -21,3 +136,192 @@ pub fn run(filter: Option<String>) {
     println!("Benchmark results will be saved to: target/criterion/");
     println!("Open target/criterion/report/index.html for the full report.");
 }
+
+/// Path of a named baseline's JSON file, following the `results` directory
+/// convention used by `sweep`'s JSON/CSV output.
+fn baseline_path(output_dir: &str, name: &str) -> PathBuf {
+    Path::new(output_dir).join(format!("baseline_{}.json", name))
+}
+
+fn write_baseline(path: &Path, timings: &[MethodTiming]) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, to_json(timings))
+}
+
+fn to_json(timings: &[MethodTiming]) -> String {
+    let entries: Vec<String> = timings
+        .iter()
+        .map(|t| {
+            format!(
+                "  {{\"method\": \"{}\", \"n\": {}, \"point_estimate_ns\": {}, \"lower_ns\": {}, \"upper_ns\": {}}}",
+                t.method, t.n, t.point_estimate_ns, t.lower_ns, t.upper_ns
+            )
+        })
+        .collect();
+    format!("[\n{}\n]\n", entries.join(",\n"))
+}
+
+/// Parse a baseline file written by [`to_json`]. Each record sits on its own
+/// line, so this reads line-by-line for the fields it needs rather than
+/// pulling in a general-purpose JSON parser for a format only this module
+/// ever writes.
+fn load_baseline(path: &Path) -> io::Result<Vec<MethodTiming>> {
+    let contents = fs::read_to_string(path)?;
+    let mut timings = Vec::new();
+
+    for line in contents.lines() {
+        if !line.contains("\"method\"") {
+            continue;
+        }
+
+        let malformed = || {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("malformed baseline line: {}", line),
+            )
+        };
+
+        timings.push(MethodTiming {
+            method: extract_quoted(line, "method").ok_or_else(malformed)?,
+            n: extract_number(line, "n").ok_or_else(malformed)? as u64,
+            point_estimate_ns: extract_number(line, "point_estimate_ns").ok_or_else(malformed)?,
+            lower_ns: extract_number(line, "lower_ns").ok_or_else(malformed)?,
+            upper_ns: extract_number(line, "upper_ns").ok_or_else(malformed)?,
+        });
+    }
+
+    Ok(timings)
+}
+
+/// Extract the string value of `"key": "value"` from a single JSON-ish line.
+fn extract_quoted(line: &str, key: &str) -> Option<String> {
+    let marker = format!("\"{}\": \"", key);
+    let start = line.find(&marker)? + marker.len();
+    let end = line[start..].find('"')? + start;
+    Some(line[start..end].to_string())
+}
+
+/// Extract the numeric value of `"key": value` from a single JSON-ish line.
+fn extract_number(line: &str, key: &str) -> Option<f64> {
+    let marker = format!("\"{}\": ", key);
+    let start = line.find(&marker)? + marker.len();
+    let rest = &line[start..];
+    let end = rest.find([',', '}']).unwrap_or(rest.len());
+    rest[..end].trim().parse().ok()
+}
+
+/// Whether two confidence intervals overlap. Non-overlapping intervals mean
+/// the change is distinguishable from sampling noise, the same rule
+/// Criterion uses to flag a run as a regression or improvement.
+fn ci_overlaps(a_lower: f64, a_upper: f64, b_lower: f64, b_upper: f64) -> bool {
+    a_lower <= b_upper && b_lower <= a_upper
+}
+
+fn percent_change(old_ns: f64, new_ns: f64) -> f64 {
+    (new_ns - old_ns) / old_ns * 100.0
+}
+
+fn print_comparison(new_timings: &[MethodTiming], old_timings: &[MethodTiming]) {
+    println!();
+    println!("📏 Comparison vs baseline");
+    println!(
+        "  {:<18} | {:>12} | {:>12} | {:>9} | {:<10}",
+        "Method", "Baseline", "Current", "Change", "Verdict"
+    );
+
+    for new in new_timings {
+        let old = match old_timings.iter().find(|o| o.method == new.method) {
+            Some(old) => old,
+            None => {
+                println!(
+                    "  {:<18} | {:>12} | {:>9.1} ns | {:>9} | {:<10}",
+                    new.method, "(none)", new.point_estimate_ns, "N/A", "New"
+                );
+                continue;
+            }
+        };
+
+        let change = percent_change(old.point_estimate_ns, new.point_estimate_ns);
+        let overlaps = ci_overlaps(old.lower_ns, old.upper_ns, new.lower_ns, new.upper_ns);
+        let verdict = if overlaps {
+            "No change"
+        } else if new.point_estimate_ns < old.point_estimate_ns {
+            "Improved"
+        } else {
+            "Regressed"
+        };
+
+        println!(
+            "  {:<18} | {:>9.1} ns | {:>9.1} ns | {:>+7.2}% | {:<10}",
+            new.method, old.point_estimate_ns, new.point_estimate_ns, change, verdict
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ci_overlaps_true_when_ranges_intersect() {
+        assert!(ci_overlaps(100.0, 200.0, 150.0, 250.0));
+        assert!(ci_overlaps(100.0, 200.0, 50.0, 120.0));
+    }
+
+    #[test]
+    fn test_ci_overlaps_false_when_ranges_are_disjoint() {
+        assert!(!ci_overlaps(100.0, 200.0, 250.0, 300.0));
+        assert!(!ci_overlaps(250.0, 300.0, 100.0, 200.0));
+    }
+
+    #[test]
+    fn test_percent_change_sign_matches_direction() {
+        assert!(percent_change(100.0, 150.0) > 0.0);
+        assert!(percent_change(150.0, 100.0) < 0.0);
+        assert_eq!(percent_change(100.0, 100.0), 0.0);
+    }
+
+    #[test]
+    fn test_baseline_json_round_trips_through_extract_helpers() {
+        let timings = vec![
+            MethodTiming {
+                method: "Iterative".to_string(),
+                n: 1000,
+                point_estimate_ns: 123.4,
+                lower_ns: 120.0,
+                upper_ns: 126.0,
+            },
+            MethodTiming {
+                method: "Matrix".to_string(),
+                n: 1000,
+                point_estimate_ns: 45.2,
+                lower_ns: 44.0,
+                upper_ns: 46.0,
+            },
+        ];
+
+        let json = to_json(&timings);
+        let mut parsed = Vec::new();
+        for line in json.lines() {
+            if !line.contains("\"method\"") {
+                continue;
+            }
+            parsed.push((
+                extract_quoted(line, "method").unwrap(),
+                extract_number(line, "n").unwrap() as u64,
+                extract_number(line, "point_estimate_ns").unwrap(),
+                extract_number(line, "lower_ns").unwrap(),
+                extract_number(line, "upper_ns").unwrap(),
+            ));
+        }
+
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0].0, "Iterative");
+        assert_eq!(parsed[0].1, 1000);
+        assert_eq!(parsed[0].2, 123.4);
+        assert_eq!(parsed[1].0, "Matrix");
+        assert_eq!(parsed[1].4, 46.0);
+    }
+}
@@ -1,6 +1,10 @@
 //! Binet formula accuracy analysis
 
 use fib_core::{closed_form, iterative};
+#[cfg(feature = "bigint")]
+use fib_core::bigint;
+#[cfg(feature = "bigint")]
+use num_bigint::BigUint;
 
 pub fn run(max_n: u64) {
     println!("--- Binet Formula Accuracy Analysis ---");
@@ -8,33 +12,59 @@ pub fn run(max_n: u64) {
     println!("Analyzing accuracy of Binet formula F(n) = (phi^n - psi^n) / sqrt(5)");
     println!();
 
-    println!("+--------+---------------------------+---------------------------+---------------+---------------+");
-    println!("| n      | Exact F(n)                | Binet F(n)                | Abs Error     | Rel Error     |");
-    println!("+--------+---------------------------+---------------------------+---------------+---------------+");
+    println!("+--------+---------------------------+---------------------------+---------------+---------------+----------+");
+    println!("| n      | Exact F(n)                | Binet F(n)                | Abs Error     | Rel Error     | DD Match |");
+    println!("+--------+---------------------------+---------------------------+---------------+---------------+----------+");
 
     let mut first_error_n: Option<u64> = None;
+    let mut first_dd_error_n: Option<u64> = None;
 
     for n in (0..=max_n).step_by(10) {
+        // With the `bigint` feature, the "Exact F(n)" column keeps growing
+        // past F(186) instead of overflowing u128; without it, it's capped
+        // at `iterative::fib_iterative`'s u128 range like the other columns.
+        #[cfg(feature = "bigint")]
+        let exact = bigint::fib_bigint(n);
+        #[cfg(not(feature = "bigint"))]
         let exact = iterative::fib_iterative(n);
+
         let binet = closed_form::fib_binet_f64(n);
         let binet_rounded = closed_form::fib_binet_rounded(n);
 
         let (abs_error, rel_error) = closed_form::binet_error_analysis(n);
 
-        let (error_marker, _is_error) = if binet_rounded != exact {
+        #[cfg(feature = "bigint")]
+        let exact_matches = BigUint::from(binet_rounded) == exact;
+        #[cfg(not(feature = "bigint"))]
+        let exact_matches = binet_rounded == exact;
+
+        let (error_marker, _is_error) = if !exact_matches {
             first_error_n.get_or_insert(n);
             ("[X]", true)
         } else {
             ("[V]", false)
         };
 
+        let binet_dd = closed_form::fib_binet_dd(n);
+        #[cfg(feature = "bigint")]
+        let dd_matches = BigUint::from(binet_dd) == exact;
+        #[cfg(not(feature = "bigint"))]
+        let dd_matches = binet_dd == exact;
+
+        let dd_marker = if dd_matches {
+            "[V]"
+        } else {
+            first_dd_error_n.get_or_insert(n);
+            "[X]"
+        };
+
         println!(
-            "| {:6} | {:25} | {:25.2} | {:13.2e} | {:9.2e} {:3} |",
-            n, exact, binet, abs_error, rel_error, error_marker
+            "| {:6} | {:25} | {:25.2} | {:13.2e} | {:9.2e} {:3} | {:8} |",
+            n, exact, binet, abs_error, rel_error, error_marker, dd_marker
         );
     }
 
-    println!("+--------+---------------------------+---------------------------+---------------+---------------+");
+    println!("+--------+---------------------------+---------------------------+---------------+---------------+----------+");
     println!();
 
     // Find exact limit
@@ -50,6 +80,15 @@ pub fn run(max_n: u64) {
         println!("   - First error observed at n = {}", first_err);
     }
 
+    println!(
+        "   - Double-double Binet (fib_binet_dd) is exact for n <= {}",
+        closed_form::MAX_ACCURATE_N_DD
+    );
+
+    if let Some(first_dd_err) = first_dd_error_n {
+        println!("   - First double-double error observed at n = {}", first_dd_err);
+    }
+
     println!();
     println!("NOTE: IEEE 754 double precision has ~15-17 significant decimal digits.");
     println!("   For larger n, use iterative or matrix methods.");
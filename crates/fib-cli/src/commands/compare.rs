@@ -1,39 +1,75 @@
 //! Compare command - compare all algorithms
 
+use crate::commands::external::ExternalTarget;
+use crate::stats::{self, SampleStats};
 use fib_core::{closed_form, iterative, matrix, recursive};
-use std::time::Instant;
+use fib_profiler::{external, stats as profiler_stats};
 
-pub fn run(n: u64, max_recursive: u64) {
-    let format_scientific = |val: u128| -> String {
-        let f = val as f64;
-        format!("{:.4e}", f)
-    };
+fn format_scientific(val: u128) -> String {
+    let f = val as f64;
+    format!("{:.4e}", f)
+}
+
+fn format_ns(ns: f64) -> String {
+    if ns >= 1_000_000.0 {
+        format!("{:.3} ms", ns / 1_000_000.0)
+    } else if ns >= 1_000.0 {
+        format!("{:.3} µs", ns / 1_000.0)
+    } else {
+        format!("{:.1} ns", ns)
+    }
+}
+
+fn print_row(name: &str, result: &str, stats: &SampleStats) {
+    println!(
+        "| {:19} | {:>41} | {:>10} | {:>10} | {:>10} | {:>10} |",
+        name,
+        result,
+        format_ns(stats.mean_ns),
+        format_ns(stats.median_ns),
+        format_ns(stats.min_ns),
+        format_ns(stats.stddev_ns),
+    );
+
+    if stats.has_high_outlier_fraction() {
+        println!(
+            "|   ⚠️  {:.1}% of samples are Tukey outliers — measurement may be noisy{:>width$}|",
+            stats.outlier_fraction() * 100.0,
+            "",
+            width = 28
+        );
+    }
+}
 
-    println!("+------------------------------------------------------------------------------+");
+pub fn run(n: u64, max_recursive: u64, samples: usize, external_specs: &[String]) {
+    println!("+--------------------------------------------------------------------------------------------------------------------+");
+    println!(
+        "|            Fibonacci Algorithm Comparison for n = {:<12} ({} samples/algorithm)           |",
+        n, samples
+    );
+    println!("+---------------------+-------------------------------------------+------------+------------+------------+------------+");
     println!(
-        "|            Fibonacci Algorithm Comparison for n = {:<12}           |",
-        n
+        "| {:19} | {:>41} | {:>10} | {:>10} | {:>10} | {:>10} |",
+        "Algorithm", "Result", "Mean", "Median", "Min", "StdDev"
     );
-    println!("+---------------------+-------------------------------------------+------------+");
-    println!("| Algorithm           | {:>41} | Time       |", "Result");
-    println!("+---------------------+-------------------------------------------+------------+");
+    println!("+---------------------+-------------------------------------------+------------+------------+------------+------------+");
 
     // Recursive (only for small n)
     if n <= max_recursive {
-        let start = Instant::now();
-        let result = recursive::fib_recursive(n);
-        let elapsed = start.elapsed();
-        println!(
-            "| {:19} | {:>41} | {:10?} |",
+        let stats = stats::sample(samples, || recursive::fib_recursive(n));
+        print_row(
             "Recursive",
-            format_scientific(result),
-            elapsed
+            &format_scientific(recursive::fib_recursive(n)),
+            &stats,
         );
     } else {
         println!(
-            "| {:19} | {:41} | {:10} |",
+            "| {:19} | {:41} | {:10} | {:10} | {:10} | {:10} |",
             "Recursive",
             format!("(skipped - n > {})", max_recursive),
+            "N/A",
+            "N/A",
+            "N/A",
             "N/A"
         );
     }
@@ -42,89 +78,76 @@ pub fn run(n: u64, max_recursive: u64) {
     // The default stack size is usually 2MB, which allows for ~20k-30k stack frames.
     let max_recursive_memo = 20_000;
     if n <= max_recursive_memo {
-        let start = Instant::now();
-        let result = recursive::fib_recursive_memo(n);
-        let elapsed = start.elapsed();
-        println!(
-            "| {:19} | {:>41} | {:10?} |",
+        let stats = stats::sample(samples, || recursive::fib_recursive_memo(n));
+        print_row(
             "Recursive+Memo",
-            format_scientific(result),
-            elapsed
+            &format_scientific(recursive::fib_recursive_memo(n)),
+            &stats,
         );
     } else {
         println!(
-            "| {:19} | {:41} | {:10} |",
+            "| {:19} | {:41} | {:10} | {:10} | {:10} | {:10} |",
             "Recursive+Memo",
             format!("(skipped - n > {})", max_recursive_memo),
+            "N/A",
+            "N/A",
+            "N/A",
             "N/A"
         );
     }
 
+    // Tail-recursive accumulator (O(1) heap allocation, unlike Recursive+Memo)
+    let stats = stats::sample(samples, || recursive::fib_recursive_acc(n));
+    print_row(
+        "Recursive+Acc",
+        &format_scientific(recursive::fib_recursive_acc(n)),
+        &stats,
+    );
+
     // Iterative
-    let start = Instant::now();
-    let result = iterative::fib_iterative(n);
-    let elapsed = start.elapsed();
-    println!(
-        "| {:19} | {:>41} | {:10?} |",
+    let stats = stats::sample(samples, || iterative::fib_iterative(n));
+    print_row(
         "Iterative",
-        format_scientific(result),
-        elapsed
+        &format_scientific(iterative::fib_iterative(n)),
+        &stats,
     );
 
     // Iterative branchless
-    let start = Instant::now();
-    let result = iterative::fib_iterative_branchless(n);
-    let elapsed = start.elapsed();
-    println!(
-        "| {:19} | {:>41} | {:10?} |",
+    let stats = stats::sample(samples, || iterative::fib_iterative_branchless(n));
+    print_row(
         "Iter. Branchless",
-        format_scientific(result),
-        elapsed
+        &format_scientific(iterative::fib_iterative_branchless(n)),
+        &stats,
     );
 
     // Matrix
-    let start = Instant::now();
-    let result = matrix::fib_matrix_fast(n);
-    let elapsed = start.elapsed();
-    println!(
-        "| {:19} | {:>41} | {:10?} |",
+    let stats = stats::sample(samples, || matrix::fib_matrix_fast(n));
+    print_row(
         "Matrix",
-        format_scientific(result),
-        elapsed
+        &format_scientific(matrix::fib_matrix_fast(n)),
+        &stats,
     );
 
     // Matrix doubling
-    let start = Instant::now();
-    let result = matrix::fib_doubling(n);
-    let elapsed = start.elapsed();
-    println!(
-        "| {:19} | {:>41} | {:10?} |",
+    let stats = stats::sample(samples, || matrix::fib_doubling(n));
+    print_row(
         "Matrix Doubling",
-        format_scientific(result),
-        elapsed
+        &format_scientific(matrix::fib_doubling(n)),
+        &stats,
     );
 
     // Binet (with accuracy warning)
-    let start = Instant::now();
+    let stats = stats::sample(samples, || closed_form::fib_binet_f64(n));
     let binet_result = closed_form::fib_binet_f64(n);
-    let elapsed = start.elapsed();
     if n <= closed_form::MAX_ACCURATE_N {
-        println!(
-            "| {:19} | {:>41} | {:10?} |",
-            "Binet (f64)",
-            format!("{:.4e}", binet_result),
-            elapsed
-        );
+        print_row("Binet (f64)", &format!("{:.4e}", binet_result), &stats);
     } else {
         let (mantissa, exponent) = closed_form::fib_binet_scientific(n);
         let output = format!("{:.4}e{}", mantissa, exponent);
-        println!(
-            "| {:19} | {:>41} | {:10?} |",
-            "Binet (f64) [!]", output, elapsed
-        );
+        print_row("Binet (f64) [!]", &output, &stats);
     }
 
-    println!("+---------------------+-------------------------------------------+------------+");
+    println!("+---------------------+-------------------------------------------+------------+------------+------------+------------+");
 
     if n > closed_form::MAX_ACCURATE_N {
         println!(
@@ -132,4 +155,66 @@ pub fn run(n: u64, max_recursive: u64) {
             closed_form::MAX_ACCURATE_N
         );
     }
+
+    if !external_specs.is_empty() {
+        print_external_section(n, samples, external_specs);
+    }
+}
+
+/// Batch size (iterations per stdin/stdout round trip) used when sampling
+/// an external-process target, to amortize pipe overhead.
+const EXTERNAL_BATCH_SIZE: u32 = 10;
+
+/// Warmup iterations run by the external process before the first sample
+const EXTERNAL_WARMUP_ITERATIONS: u32 = 50;
+
+fn print_external_section(n: u64, samples: usize, external_specs: &[String]) {
+    println!();
+    println!("🔌 External-Process Targets (line protocol over stdin/stdout)");
+    println!("+---------------------+----------------------+------------------------------+------------------+");
+    println!(
+        "| {:19} | {:>20} | {:>28} | {:>16} |",
+        "Target", "Mean", "95% CI", "Outliers"
+    );
+    println!("+---------------------+----------------------+------------------------------+------------------+");
+
+    for spec in external_specs {
+        let target = match ExternalTarget::parse(spec) {
+            Ok(target) => target,
+            Err(e) => {
+                println!("| ❌ {}", e);
+                continue;
+            }
+        };
+
+        match external::benchmark_samples(
+            &target.command,
+            n,
+            EXTERNAL_WARMUP_ITERATIONS,
+            EXTERNAL_BATCH_SIZE,
+            samples,
+        ) {
+            Ok(durations_ns) => {
+                let ci = profiler_stats::bootstrap_mean_ci(
+                    &durations_ns,
+                    profiler_stats::DEFAULT_BOOTSTRAP_RESAMPLES,
+                );
+                let outliers = profiler_stats::classify_outliers(&durations_ns);
+                println!(
+                    "| {:19} | {:>20} | [{:>12}, {:>12}] | {:>7} mild/{:>2} sev |",
+                    target.name,
+                    format_ns(ci.point_estimate_ns),
+                    format_ns(ci.lower_ns),
+                    format_ns(ci.upper_ns),
+                    outliers.mild,
+                    outliers.severe
+                );
+            }
+            Err(e) => {
+                println!("| {:19} | ⚠️ {}", target.name, e);
+            }
+        }
+    }
+
+    println!("+---------------------+----------------------+------------------------------+------------------+");
 }
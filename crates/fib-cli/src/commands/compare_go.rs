@@ -1,32 +1,47 @@
 //! Compare Go command - compares Rust vs Go Fibonacci implementations
 
-use fib_go::{compare_implementations, format_comparison_table, get_go_version, is_go_available};
+use fib_go::{
+    compare_implementations, format_comparison_table, get_go_version, is_go_available, GoBackend,
+};
 
 /// Run the compare-go command
-pub fn run(n: u64, iterations: u32) {
+pub fn run(n: u64, iterations: u32, backend: &str) {
     println!("🔬 Rust vs Go Fibonacci Comparison");
     println!("===================================");
     println!();
 
-    // Check Go availability
-    let go_version = get_go_version();
-    println!("📦 Go Version: {}", go_version);
-
-    if !is_go_available() {
-        println!();
-        println!("⚠️  Note: Running with Rust stub (CGO not available)");
-        println!("   To use native Go implementation:");
-        println!("   1. Install MinGW-w64 (GCC for Windows)");
-        println!("   2. Add GCC to PATH");
-        println!("   3. Rebuild with: cargo build -p fib-go");
-        println!();
+    let backend: GoBackend = match backend.parse() {
+        Ok(b) => b,
+        Err(e) => {
+            eprintln!("❌ Error: {}", e);
+            eprintln!("Available backends: ffi, subprocess");
+            std::process::exit(1);
+        }
+    };
+
+    if backend == GoBackend::Ffi {
+        // Check Go availability
+        let go_version = get_go_version();
+        println!("📦 Go Version: {}", go_version);
+
+        if !is_go_available() {
+            println!();
+            println!("⚠️  Note: Running with Rust stub (CGO not available)");
+            println!("   To use native Go implementation:");
+            println!("   1. Install MinGW-w64 (GCC for Windows)");
+            println!("   2. Add GCC to PATH");
+            println!("   3. Rebuild with: cargo build -p fib-go");
+            println!();
+        }
+    } else {
+        println!("📦 Backend: subprocess (go run, falling back to python3)");
     }
 
     println!("📊 Parameters: n={}, iterations={}", n, iterations);
     println!();
 
     // Run comparison
-    let results = compare_implementations(n, iterations);
+    let results = compare_implementations(n, iterations, backend);
 
     // Display results
     let table = format_comparison_table(&results);
@@ -0,0 +1,177 @@
+//! External-process benchmark harness
+//!
+//! Generalizes the one-off Rust-vs-Go comparison (`compare_go`) into a
+//! reusable harness that can spawn any configured executable or script — Go,
+//! Python, C, Node, … — pass the Fibonacci index via argv, capture stdout
+//! (the result) and measured wall time, and compare against the in-crate
+//! Rust reference implementation. Each target is specified as
+//! `--target name=./path/to/executable` (repeatable).
+
+use fib_core::FibMethod;
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+/// One external program to benchmark: a display name plus the command to run
+#[derive(Debug, Clone)]
+pub struct ExternalTarget {
+    pub name: String,
+    pub command: String,
+}
+
+impl ExternalTarget {
+    /// Parse a `--target name=command` argument
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let (name, command) = spec
+            .split_once('=')
+            .ok_or_else(|| format!("invalid --target '{}', expected name=command", spec))?;
+
+        if name.is_empty() || command.is_empty() {
+            return Err(format!("invalid --target '{}', expected name=command", spec));
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            command: command.to_string(),
+        })
+    }
+}
+
+/// Outcome of benchmarking one external target
+struct ExternalResult {
+    name: String,
+    result: Option<u128>,
+    avg_time: Duration,
+    matches_reference: bool,
+    error: Option<String>,
+}
+
+/// Run the external-process comparison for the given targets
+///
+/// Each target is spawned `iterations` times via `std::process::Command`,
+/// receiving `n` as its sole argument. F(n) is computed in-crate via
+/// `FibMethod::Matrix` and used as the correctness reference.
+pub fn run(target_specs: &[String], n: u64, iterations: u32) {
+    println!("🔬 External-Process Fibonacci Comparison");
+    println!("=========================================");
+    println!();
+
+    if target_specs.is_empty() {
+        eprintln!("❌ Error: no targets given. Use --target name=./path/to/executable");
+        return;
+    }
+
+    let reference = FibMethod::Matrix.calculate(n);
+    println!("📊 Parameters: n={}, iterations={}", n, iterations);
+    println!("📐 Rust reference F({}) = {}", n, reference);
+    println!();
+
+    let mut results = Vec::new();
+    for spec in target_specs {
+        match ExternalTarget::parse(spec) {
+            Ok(target) => results.push(benchmark_target(&target, n, iterations, reference)),
+            Err(e) => eprintln!("❌ {}", e),
+        }
+    }
+
+    print_table(&results);
+}
+
+fn benchmark_target(
+    target: &ExternalTarget,
+    n: u64,
+    iterations: u32,
+    reference: u128,
+) -> ExternalResult {
+    let mut total = Duration::ZERO;
+    let mut last_result = None;
+    let mut error = None;
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let output = Command::new(&target.command).arg(n.to_string()).output();
+        let elapsed = start.elapsed();
+
+        match output {
+            Ok(out) if out.status.success() => {
+                total += elapsed;
+                last_result = String::from_utf8_lossy(&out.stdout).trim().parse().ok();
+            }
+            Ok(out) => {
+                error = Some(format!("exited with {:?}", out.status.code()));
+                break;
+            }
+            Err(e) => {
+                error = Some(format!("failed to spawn: {}", e));
+                break;
+            }
+        }
+    }
+
+    let avg_time = if error.is_none() && iterations > 0 {
+        total / iterations
+    } else {
+        Duration::ZERO
+    };
+
+    ExternalResult {
+        name: target.name.clone(),
+        matches_reference: last_result == Some(reference),
+        result: last_result,
+        avg_time,
+        error,
+    }
+}
+
+fn print_table(results: &[ExternalResult]) {
+    println!(
+        "| {:15} | {:>20} | {:>12} | {:8} |",
+        "Target", "Result", "Avg Time", "Correct"
+    );
+    println!("|{:-<17}|{:-<22}|{:-<14}|{:-<10}|", "", "", "", "");
+
+    for r in results {
+        if let Some(err) = &r.error {
+            println!("| {:15} | {:>20} | {:>12} | {:8} |", r.name, "-", "-", "⚠️ error");
+            println!("    ↳ {}", err);
+            continue;
+        }
+
+        let result_str = r
+            .result
+            .map(|v| v.to_string())
+            .unwrap_or_else(|| "(unparsable)".to_string());
+        let correct = if r.matches_reference { "✅" } else { "❌" };
+
+        println!(
+            "| {:15} | {:>20} | {:>12?} | {:8} |",
+            r.name, result_str, r.avg_time, correct
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_valid_target() {
+        let target = ExternalTarget::parse("go=./bin/fib_go").unwrap();
+        assert_eq!(target.name, "go");
+        assert_eq!(target.command, "./bin/fib_go");
+    }
+
+    #[test]
+    fn test_parse_missing_equals() {
+        assert!(ExternalTarget::parse("go-bin-fib").is_err());
+    }
+
+    #[test]
+    fn test_parse_empty_name() {
+        assert!(ExternalTarget::parse("=./bin/fib_go").is_err());
+    }
+
+    #[test]
+    fn test_parse_empty_command() {
+        assert!(ExternalTarget::parse("go=").is_err());
+    }
+}
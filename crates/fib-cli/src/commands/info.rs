@@ -111,7 +111,7 @@ pub fn run(method: &str) {
             }
             Err(e) => {
                 eprintln!("❌ Error: {}", e);
-                eprintln!("Available methods: recursive, recursive_memo, iterative, matrix, fast_doubling, binet");
+                eprintln!("Available methods: recursive, recursive_memo, recursive_acc, iterative, matrix, fast_doubling, binet");
             }
         }
     }
@@ -26,18 +26,18 @@ pub fn run(n: u64, method_str: &str) {
 
     let final_allocs = ALLOCATOR.get_allocation_count();
     let final_bytes = ALLOCATOR.get_current_usage(); // This might be back to 0 if everything was dropped
-
-    // For recursive memo, the vector represents peak usage basically.
-    // Since we don't have peak tracking yet, we rely on "total allocations made".
+    let peak_bytes = ALLOCATOR.get_peak_usage();
 
     let allocs_made = final_allocs - initial_allocs;
     let net_bytes = final_bytes.saturating_sub(initial_bytes);
+    let peak_since_start = peak_bytes.saturating_sub(initial_bytes);
 
     println!("Result: {}", result);
     println!();
     println!("📊 Statistics:");
     println!("  Total Allocations: {}", allocs_made);
     println!("  Net Bytes Leaked/Held: {}", net_bytes);
+    println!("  Peak Bytes (measured): {}", peak_since_start);
 
     println!();
     println!("📋 Theoretical Complexity:");
@@ -5,10 +5,13 @@ pub mod binet_analysis;
 pub mod calc;
 pub mod compare;
 pub mod compare_go;
+pub mod external;
 pub mod info;
 pub mod memory;
+pub mod pisano;
 pub mod report;
 pub mod sequence;
+pub mod sweep;
 
 #[cfg(feature = "simd")]
 pub mod simd;
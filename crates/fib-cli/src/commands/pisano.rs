@@ -0,0 +1,46 @@
+//! Pisano period command - inspect the cycle length of F(n) mod m
+
+use fib_core::modular::{pisano_period_factored, pisano_period_u128};
+
+/// Run the pisano-period command.
+///
+/// `factors`, if non-empty, is a list of `prime^exponent` pairs that multiply
+/// out to `m`; when supplied, the period is also computed via the
+/// multiplicative-structure route and checked against direct iteration,
+/// which always runs.
+pub fn run(m: u128, factors: &[(u128, u32)]) {
+    println!("🔁 Pisano Period Analysis");
+    println!("=========================");
+    println!("  Modulus m = {}", m);
+    println!();
+
+    let direct = pisano_period_u128(m);
+    println!("  π({}) = {} (direct iteration)", m, direct);
+
+    if !factors.is_empty() {
+        let product: u128 = factors.iter().map(|&(p, k)| p.pow(k)).product();
+        if product != m {
+            eprintln!(
+                "❌ Error: factors {:?} multiply to {}, not m = {}",
+                factors, product, m
+            );
+            return;
+        }
+
+        let factored = pisano_period_factored(factors);
+        println!(
+            "  π({}) = {} (factored: {:?})",
+            m, factored, factors
+        );
+
+        if factored != direct {
+            eprintln!(
+                "⚠️  Mismatch between direct and factored computation ({} vs {})",
+                direct, factored
+            );
+        }
+    }
+
+    println!();
+    println!("NOTE: F(n) mod m repeats with period π(m), so F(n) mod m == F(n mod π(m)) mod m.");
+}
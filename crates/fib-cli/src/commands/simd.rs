@@ -74,6 +74,18 @@ pub fn run(batch: &[u64], show_info: bool, compare: bool) {
             speedup,
             if speedup > 1.0 { "🚀" } else { "" }
         );
+
+        let (linear_ns, doubling_ns) = calc.benchmark_linear_vs_doubling(batch, iterations);
+        let doubling_speedup = if doubling_ns > 0 {
+            linear_ns as f64 / doubling_ns as f64
+        } else {
+            0.0
+        };
+
+        println!("\n📈 SIMD Strategy Comparison (linear vs fast-doubling):");
+        println!("   Linear:   {} ns/batch", linear_ns);
+        println!("   Doubling: {} ns/batch", doubling_ns);
+        println!("   Speedup:  {:.2}x", doubling_speedup);
     }
 
     println!("\n{}", "=".repeat(50));
@@ -0,0 +1,133 @@
+//! Sweep command - benchmark every algorithm across a range of n values
+//!
+//! Unlike `compare`, which times every algorithm at a single n, `sweep` runs
+//! the same algorithm x n timing grid used by `compare` across many n values,
+//! so scaling curves (O(n) vs O(log n) vs O(2^n)) can be read off the results
+//! instead of eyeballed from a single data point.
+
+use crate::stats;
+use fib_core::FibMethod;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// One measured (algorithm, n) cell in the sweep grid
+struct SweepPoint {
+    n: u64,
+    algorithm: &'static str,
+    mean_ns: f64,
+}
+
+/// Algorithms that are only practical for small n (avoid O(2^n) blowups)
+const MAX_RECURSIVE_N: u64 = 30;
+
+pub fn run(points: &[u64], samples: usize, output_dir: &str) {
+    if points.is_empty() {
+        eprintln!("❌ Error: no n values given. Use --points or --start/--stop/--step.");
+        return;
+    }
+
+    println!("📈 Fibonacci Algorithm Sweep");
+    println!("============================");
+    println!(
+        "  n values: {:?} ({} samples/cell)",
+        points, samples
+    );
+    println!();
+
+    let methods = [
+        FibMethod::Recursive,
+        FibMethod::RecursiveMemo,
+        FibMethod::RecursiveAcc,
+        FibMethod::Iterative,
+        FibMethod::IterativeBranchless,
+        FibMethod::Matrix,
+        FibMethod::FastDoubling,
+        FibMethod::Binet,
+    ];
+
+    let mut grid: Vec<SweepPoint> = Vec::new();
+
+    for &n in points {
+        for method in methods {
+            if method == FibMethod::Recursive && n > MAX_RECURSIVE_N {
+                continue;
+            }
+
+            let stats = stats::sample(samples, || method.calculate(n));
+            grid.push(SweepPoint {
+                n,
+                algorithm: method.name(),
+                mean_ns: stats.mean_ns,
+            });
+        }
+    }
+
+    print_table(&grid, points, &methods);
+
+    if let Err(e) = write_results(&grid, output_dir) {
+        eprintln!("❌ Error writing sweep results: {}", e);
+    }
+}
+
+fn print_table(grid: &[SweepPoint], points: &[u64], methods: &[FibMethod]) {
+    print!("| {:>10} |", "n");
+    for method in methods {
+        print!(" {:>16} |", method.name());
+    }
+    println!();
+
+    for &n in points {
+        print!("| {:>10} |", n);
+        for method in methods {
+            let cell = grid
+                .iter()
+                .find(|p| p.n == n && p.algorithm == method.name())
+                .map(|p| format!("{:.1} ns", p.mean_ns))
+                .unwrap_or_else(|| "skipped".to_string());
+            print!(" {:>16} |", cell);
+        }
+        println!();
+    }
+    println!();
+}
+
+/// Write the sweep grid as both JSON and CSV into `output_dir`, following the
+/// `results` directory convention consumed by `fib_viz::generate_report`.
+fn write_results(grid: &[SweepPoint], output_dir: &str) -> io::Result<()> {
+    let dir = Path::new(output_dir);
+    fs::create_dir_all(dir)?;
+
+    let json_path = dir.join("sweep_comparison.json");
+    let json = to_json(grid);
+    fs::write(&json_path, json)?;
+    println!("   ✓ {}", json_path.display());
+
+    let csv_path = dir.join("sweep_comparison.csv");
+    let csv = to_csv(grid);
+    fs::write(&csv_path, csv)?;
+    println!("   ✓ {}", csv_path.display());
+
+    Ok(())
+}
+
+fn to_json(grid: &[SweepPoint]) -> String {
+    let entries: Vec<String> = grid
+        .iter()
+        .map(|p| {
+            format!(
+                "  {{\"n\": {}, \"algorithm\": \"{}\", \"mean_ns\": {}}}",
+                p.n, p.algorithm, p.mean_ns
+            )
+        })
+        .collect();
+    format!("[\n{}\n]\n", entries.join(",\n"))
+}
+
+fn to_csv(grid: &[SweepPoint]) -> String {
+    let mut csv = String::from("n,algorithm,mean_ns\n");
+    for p in grid {
+        csv.push_str(&format!("{},{},{}\n", p.n, p.algorithm, p.mean_ns));
+    }
+    csv
+}
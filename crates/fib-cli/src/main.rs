@@ -10,6 +10,7 @@ use fib_core::allocator::TrackingAllocator;
 static ALLOCATOR: TrackingAllocator = TrackingAllocator::new();
 
 mod commands;
+mod stats;
 
 #[derive(Parser)]
 #[command(name = "fib-bench")]
@@ -52,6 +53,16 @@ enum Commands {
         /// Maximum n for recursive (to avoid long waits)
         #[arg(long, default_value = "30")]
         max_recursive: u64,
+
+        /// Number of timing samples to collect per algorithm
+        #[arg(long, default_value = "1000")]
+        samples: usize,
+
+        /// Additional external-process target to compare against, as
+        /// name=command (repeatable); speaks the iteration-count/elapsed-ns
+        /// line protocol over stdin/stdout (see `fib_profiler::external`)
+        #[arg(long = "external")]
+        external: Vec<String>,
     },
 
     /// Run the Criterion benchmarks
@@ -59,6 +70,36 @@ enum Commands {
         /// Filter benchmarks by name
         #[arg(short, long)]
         filter: Option<String>,
+
+        /// Warm-up duration (ms) used to estimate per-iteration cost before
+        /// sizing the measurement phase
+        #[arg(long, default_value = "500")]
+        warm_up_ms: u64,
+
+        /// Target wall-clock duration (ms) for the measurement phase
+        #[arg(long, default_value = "1000")]
+        measure_ms: u64,
+
+        /// Minimum number of measured samples regardless of the timing budget
+        #[arg(long, default_value = "10")]
+        min_samples: usize,
+
+        /// The Fibonacci index to time each method at
+        #[arg(short, long, default_value = "1000")]
+        n: u64,
+
+        /// Save (or, with --compare, load) a named baseline under this name
+        #[arg(long)]
+        baseline: Option<String>,
+
+        /// Compare this run's timings against the saved --baseline instead
+        /// of only recording a new one
+        #[arg(long)]
+        compare: bool,
+
+        /// Output directory for the baseline JSON file
+        #[arg(short, long, default_value = "results")]
+        output: String,
     },
 
     /// Show algorithm complexity information
@@ -86,6 +127,19 @@ enum Commands {
         max_n: u64,
     },
 
+    /// Find the Pisano period (cycle length of F(n) mod m)
+    PisanoPeriod {
+        /// The modulus m
+        #[arg(short, long)]
+        m: u128,
+
+        /// Known prime factorization of m as comma-separated prime:exponent
+        /// pairs (e.g. "2:1,5:1" for m=10), to compute via the faster
+        /// multiplicative-structure route in addition to direct iteration
+        #[arg(long, value_delimiter = ',')]
+        factors: Vec<String>,
+    },
+
     /// Analyze memory usage
     Memory {
         /// The Fibonacci index to calculate
@@ -108,6 +162,33 @@ enum Commands {
         output: String,
     },
 
+    /// Benchmark every algorithm across a range of n values
+    Sweep {
+        /// Comma-separated list of n values (overrides --start/--stop/--step)
+        #[arg(long, value_delimiter = ',')]
+        points: Option<Vec<u64>>,
+
+        /// First n value of the range (used when --points is not given)
+        #[arg(long)]
+        start: Option<u64>,
+
+        /// Last n value of the range, inclusive (used when --points is not given)
+        #[arg(long)]
+        stop: Option<u64>,
+
+        /// Step between n values in the range
+        #[arg(long, default_value = "10")]
+        step: u64,
+
+        /// Number of timing samples per (algorithm, n) cell
+        #[arg(long, default_value = "100")]
+        samples: usize,
+
+        /// Output directory for JSON/CSV results
+        #[arg(short, long, default_value = "results")]
+        output: String,
+    },
+
     /// Compare Rust vs Go Fibonacci implementations
     CompareGo {
         /// The Fibonacci index to calculate
@@ -117,6 +198,26 @@ enum Commands {
         /// Number of iterations for timing
         #[arg(short, long, default_value = "100")]
         iterations: u32,
+
+        /// How to reach the Go side: "ffi" (CGO bridge, default) or
+        /// "subprocess" (go run / python3 child process, no CGO required)
+        #[arg(short, long, default_value = "ffi")]
+        backend: String,
+    },
+
+    /// Benchmark external-process Fibonacci implementations (Go, Python, …)
+    External {
+        /// Target to benchmark, as name=command (repeatable)
+        #[arg(long = "target")]
+        targets: Vec<String>,
+
+        /// The Fibonacci index to calculate
+        #[arg(short, long, default_value = "1000")]
+        n: u64,
+
+        /// Number of iterations for timing
+        #[arg(short, long, default_value = "100")]
+        iterations: u32,
     },
 
     /// SIMD-accelerated batch Fibonacci calculation
@@ -148,11 +249,27 @@ fn main() {
         } => {
             commands::calc::run(n, &method, time, json);
         }
-        Commands::Compare { n, max_recursive } => {
-            commands::compare::run(n, max_recursive);
+        Commands::Compare {
+            n,
+            max_recursive,
+            samples,
+            external,
+        } => {
+            commands::compare::run(n, max_recursive, samples, &external);
         }
-        Commands::Bench { filter } => {
-            commands::bench::run(filter);
+        Commands::Bench {
+            filter,
+            warm_up_ms,
+            measure_ms,
+            min_samples,
+            n,
+            baseline,
+            compare,
+            output,
+        } => {
+            commands::bench::run(
+                filter, warm_up_ms, measure_ms, min_samples, n, baseline, compare, &output,
+            );
         }
         Commands::Info { method } => {
             commands::info::run(&method);
@@ -163,14 +280,74 @@ fn main() {
         Commands::BinetAnalysis { max_n } => {
             commands::binet_analysis::run(max_n);
         }
+        Commands::PisanoPeriod { m, factors } => {
+            let parsed_factors: Result<Vec<(u128, u32)>, String> = factors
+                .iter()
+                .map(|f| {
+                    let (p, k) = f
+                        .split_once(':')
+                        .ok_or_else(|| format!("invalid factor '{}', expected prime:exponent", f))?;
+                    let p: u128 = p
+                        .parse()
+                        .map_err(|_| format!("invalid prime in factor '{}'", f))?;
+                    let k: u32 = k
+                        .parse()
+                        .map_err(|_| format!("invalid exponent in factor '{}'", f))?;
+                    Ok((p, k))
+                })
+                .collect();
+
+            match parsed_factors {
+                Ok(factors) => commands::pisano::run(m, &factors),
+                Err(e) => {
+                    eprintln!("❌ Error: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
         Commands::Memory { n, method } => {
             commands::memory::run(n, &method);
         }
         Commands::Report { input, output } => {
             commands::report::run(&input, &output);
         }
-        Commands::CompareGo { n, iterations } => {
-            commands::compare_go::run(n, iterations);
+        Commands::Sweep {
+            points,
+            start,
+            stop,
+            step,
+            samples,
+            output,
+        } => {
+            let points = match points {
+                Some(p) => p,
+                None => match (start, stop) {
+                    (Some(start), Some(stop)) if step > 0 => {
+                        (start..=stop).step_by(step as usize).collect()
+                    }
+                    _ => {
+                        eprintln!(
+                            "❌ Error: provide either --points or both --start and --stop (with a positive --step)"
+                        );
+                        return;
+                    }
+                },
+            };
+            commands::sweep::run(&points, samples, &output);
+        }
+        Commands::CompareGo {
+            n,
+            iterations,
+            backend,
+        } => {
+            commands::compare_go::run(n, iterations, &backend);
+        }
+        Commands::External {
+            targets,
+            n,
+            iterations,
+        } => {
+            commands::external::run(&targets, n, iterations);
         }
         #[cfg(feature = "simd")]
         Commands::Simd {
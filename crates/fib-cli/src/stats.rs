@@ -0,0 +1,184 @@
+//! Sampling statistical timer
+//!
+//! A small micro-benchmark utility, inspired by sample-based runners like
+//! Criterion: run a short warmup to prime caches/branch predictors, then
+//! collect a configurable number of timing samples for a closure and report
+//! summary statistics (mean, median, min, standard deviation) instead of a
+//! single jittery `Instant::now()` reading.
+
+use std::time::Instant;
+
+/// Default number of timed samples collected per algorithm
+pub const DEFAULT_SAMPLES: usize = 1000;
+
+/// Number of untimed warmup iterations run before sampling begins
+const WARMUP_ITERATIONS: usize = 10;
+
+/// Fraction of outlier samples (by Tukey fences) above which we warn the user
+/// that measurement interference may be skewing the results
+const HIGH_OUTLIER_FRACTION: f64 = 0.10;
+
+/// Summary statistics for a batch of timing samples, in nanoseconds
+#[derive(Debug, Clone, Copy)]
+pub struct SampleStats {
+    pub samples: usize,
+    pub mean_ns: f64,
+    pub median_ns: f64,
+    pub min_ns: f64,
+    pub stddev_ns: f64,
+    pub outliers: usize,
+}
+
+impl SampleStats {
+    /// Fraction of samples that fall outside the Tukey inner fences
+    pub fn outlier_fraction(&self) -> f64 {
+        if self.samples == 0 {
+            0.0
+        } else {
+            self.outliers as f64 / self.samples as f64
+        }
+    }
+
+    /// Whether the outlier fraction is high enough to warrant a warning
+    pub fn has_high_outlier_fraction(&self) -> bool {
+        self.outlier_fraction() > HIGH_OUTLIER_FRACTION
+    }
+}
+
+/// Run `f` repeatedly, collecting `samples` timing measurements (after a
+/// short warmup), and return summary statistics in nanoseconds.
+///
+/// `f` is invoked `WARMUP_ITERATIONS + samples` times in total. Its return
+/// value is passed through `black_box` so the optimizer can't elide the call.
+pub fn sample<F, T>(samples: usize, mut f: F) -> SampleStats
+where
+    F: FnMut() -> T,
+{
+    for _ in 0..WARMUP_ITERATIONS {
+        let _ = std::hint::black_box(f());
+    }
+
+    let mut durations_ns: Vec<f64> = Vec::with_capacity(samples);
+    for _ in 0..samples {
+        let start = Instant::now();
+        let result = f();
+        let elapsed = start.elapsed();
+        std::hint::black_box(result);
+        durations_ns.push(elapsed.as_nanos() as f64);
+    }
+
+    summarize(&durations_ns)
+}
+
+/// Compute summary statistics (mean, median, min, stddev, Tukey outliers)
+/// for a slice of timing samples in nanoseconds.
+fn summarize(durations_ns: &[f64]) -> SampleStats {
+    let samples = durations_ns.len();
+    if samples == 0 {
+        return SampleStats {
+            samples: 0,
+            mean_ns: 0.0,
+            median_ns: 0.0,
+            min_ns: 0.0,
+            stddev_ns: 0.0,
+            outliers: 0,
+        };
+    }
+
+    let mut sorted = durations_ns.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let sum: f64 = sorted.iter().sum();
+    let mean_ns = sum / samples as f64;
+    let min_ns = sorted[0];
+    let median_ns = percentile(&sorted, 0.5);
+
+    let variance = sorted
+        .iter()
+        .map(|&v| {
+            let diff = v - mean_ns;
+            diff * diff
+        })
+        .sum::<f64>()
+        / samples as f64;
+    let stddev_ns = variance.sqrt();
+
+    // Tukey fences: outliers lie outside [Q1 - 1.5*IQR, Q3 + 1.5*IQR]
+    let q1 = percentile(&sorted, 0.25);
+    let q3 = percentile(&sorted, 0.75);
+    let iqr = q3 - q1;
+    let lower_fence = q1 - 1.5 * iqr;
+    let upper_fence = q3 + 1.5 * iqr;
+    let outliers = sorted
+        .iter()
+        .filter(|&&v| v < lower_fence || v > upper_fence)
+        .count();
+
+    SampleStats {
+        samples,
+        mean_ns,
+        median_ns,
+        min_ns,
+        stddev_ns,
+        outliers,
+    }
+}
+
+/// Linear-interpolated percentile of an already-sorted slice
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.len() == 1 {
+        return sorted[0];
+    }
+
+    let rank = p * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    if lower == upper {
+        sorted[lower]
+    } else {
+        let frac = rank - lower as f64;
+        sorted[lower] + (sorted[upper] - sorted[lower]) * frac
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summarize_constant_samples() {
+        let durations = vec![100.0; 50];
+        let stats = summarize(&durations);
+        assert_eq!(stats.samples, 50);
+        assert_eq!(stats.mean_ns, 100.0);
+        assert_eq!(stats.median_ns, 100.0);
+        assert_eq!(stats.min_ns, 100.0);
+        assert_eq!(stats.stddev_ns, 0.0);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn test_summarize_detects_outliers() {
+        // 20 tightly clustered samples plus one huge spike
+        let mut durations: Vec<f64> = (0..20).map(|_| 100.0).collect();
+        durations.push(100_000.0);
+        let stats = summarize(&durations);
+        assert_eq!(stats.outliers, 1);
+        assert!(stats.outlier_fraction() > 0.0);
+        assert!(!stats.has_high_outlier_fraction());
+    }
+
+    #[test]
+    fn test_summarize_empty() {
+        let stats = summarize(&[]);
+        assert_eq!(stats.samples, 0);
+        assert_eq!(stats.mean_ns, 0.0);
+    }
+
+    #[test]
+    fn test_sample_collects_requested_count() {
+        let stats = sample(20, || 1 + 1);
+        assert_eq!(stats.samples, 20);
+        assert!(stats.mean_ns >= 0.0);
+    }
+}
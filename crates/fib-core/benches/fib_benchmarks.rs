@@ -2,8 +2,8 @@
 //!
 //! Run with: `cargo bench`
 
-use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
-use fib_core::{closed_form, iterative, matrix, recursive};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use fib_core::{closed_form, iterative, matrix, number_theory, recursive, FibMethod};
 
 /// Benchmark comparing algorithm complexities
 fn complexity_comparison(c: &mut Criterion) {
@@ -49,6 +49,11 @@ fn large_n_scaling(c: &mut Criterion) {
     group.sample_size(50);
 
     for n in [100, 500, 1000, 5000, 10000].iter() {
+        // F(n) has ~0.694*n bits; report that as a byte-based throughput so
+        // the matrix/doubling curves expose their true big-integer cost as a
+        // work-per-second rate instead of a flat per-call time.
+        group.throughput(Throughput::Bytes((0.694 * *n as f64 / 8.0) as u64));
+
         group.bench_with_input(BenchmarkId::new("iterative", n), n, |b, &n| {
             b.iter(|| iterative::fib_iterative(black_box(n)))
         });
@@ -92,14 +97,17 @@ fn batch_operations(c: &mut Criterion) {
     let medium_batch: Vec<u64> = (1..=50).collect();
     let large_batch: Vec<u64> = (1..=100).collect();
 
+    group.throughput(Throughput::Elements(small_batch.len() as u64));
     group.bench_function("batch_10", |b| {
         b.iter(|| iterative::fib_iterative_batch(black_box(&small_batch)))
     });
 
+    group.throughput(Throughput::Elements(medium_batch.len() as u64));
     group.bench_function("batch_50", |b| {
         b.iter(|| iterative::fib_iterative_batch(black_box(&medium_batch)))
     });
 
+    group.throughput(Throughput::Elements(large_batch.len() as u64));
     group.bench_function("batch_100", |b| {
         b.iter(|| iterative::fib_iterative_batch(black_box(&large_batch)))
     });
@@ -115,6 +123,8 @@ fn cache_vs_direct(c: &mut Criterion) {
     let cache = iterative::FibonacciCache::new(100);
     let queries: Vec<u64> = vec![10, 25, 50, 75, 100];
 
+    group.throughput(Throughput::Elements(queries.len() as u64));
+
     group.bench_function("direct_lookups", |b| {
         b.iter(|| {
             queries
@@ -152,6 +162,95 @@ fn modular_arithmetic(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark `fib_gcd(m, n)` (the `gcd(F(m), F(n)) = F(gcd(m, n))`
+/// identity) against the naive path of computing `F(m)` and `F(n)` and then
+/// taking their GCD, to demonstrate how much the identity saves once `m`/`n`
+/// get large relative to their GCD. Both paths use the `u128` routines, so
+/// for these pairs (all past F(186)) the values wrap and only the cost of the
+/// two paths is compared.
+fn number_theory_gcd(c: &mut Criterion) {
+    let mut group = c.benchmark_group("number_theory");
+    group.sample_size(50);
+
+    for (m, n) in [(1000, 1500), (5000, 8000), (10000, 10001)].iter() {
+        group.bench_with_input(
+            BenchmarkId::new("fib_gcd", format!("{}_{}", m, n)),
+            &(*m, *n),
+            |b, &(m, n)| b.iter(|| number_theory::fib_gcd(black_box(m), black_box(n))),
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("naive_fib_then_gcd", format!("{}_{}", m, n)),
+            &(*m, *n),
+            |b, &(m, n)| {
+                b.iter(|| {
+                    let fib_m = matrix::fib_doubling(black_box(m));
+                    let fib_n = matrix::fib_doubling(black_box(n));
+                    number_theory::gcd(fib_m, fib_n)
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+/// All `FibMethod` variants except the recursive ones, which get their own
+/// (much smaller) ladder below so their exponential cost doesn't dominate.
+const NON_RECURSIVE_METHODS: &[FibMethod] = &[
+    FibMethod::Iterative,
+    FibMethod::IterativeBranchless,
+    FibMethod::Matrix,
+    FibMethod::FastDoubling,
+    FibMethod::Binet,
+];
+
+const RECURSIVE_METHODS: &[FibMethod] = &[
+    FibMethod::Recursive,
+    FibMethod::RecursiveMemo,
+    FibMethod::RecursiveAcc,
+];
+
+/// Benchmark every `FibMethod` across a shared `n` ladder, one group per
+/// method, so the complexity/notes surfaced by the `info` command (e.g.
+/// "Matrix: best for large n", "IterativeBranchless: CPU pipeline
+/// optimized") can be checked empirically. Falls back to `calculate_bigint`
+/// once `n` would overflow `u128` (n > 186).
+fn fib_method_sweep(c: &mut Criterion) {
+    const SIZES: &[u64] = &[10, 20, 30, 50, 100, 1000, 10000];
+
+    for method in NON_RECURSIVE_METHODS {
+        let mut group = c.benchmark_group(method.name());
+        group.sample_size(50);
+
+        for n in SIZES {
+            group.bench_with_input(BenchmarkId::new(method.name(), n), n, |b, &n| {
+                if n <= 186 {
+                    b.iter(|| method.calculate(black_box(n)))
+                } else {
+                    b.iter(|| method.calculate_bigint(black_box(n)))
+                }
+            });
+        }
+
+        group.finish();
+    }
+
+    // Recursive/memoized grow too fast for the shared ladder above.
+    let mut group = c.benchmark_group("recursive_methods");
+    group.sample_size(50);
+
+    for method in RECURSIVE_METHODS {
+        for n in [10, 20, 25].iter() {
+            group.bench_with_input(BenchmarkId::new(method.name(), n), n, |b, &n| {
+                b.iter(|| method.calculate(black_box(n)))
+            });
+        }
+    }
+
+    group.finish();
+}
+
 /// Benchmark SIMD batch operations (requires simd feature)
 #[cfg(feature = "simd")]
 fn simd_vs_scalar(c: &mut Criterion) {
@@ -217,6 +316,8 @@ criterion_group!(
     batch_operations,
     cache_vs_direct,
     modular_arithmetic,
+    fib_method_sweep,
+    number_theory_gcd,
 );
 
 #[cfg(feature = "simd")]
@@ -228,6 +329,8 @@ criterion_group!(
     batch_operations,
     cache_vs_direct,
     modular_arithmetic,
+    fib_method_sweep,
+    number_theory_gcd,
     simd_vs_scalar,
 );
 
@@ -0,0 +1,282 @@
+//! Hardware-event Criterion benchmarks for `complexity_comparison` and
+//! `large_n_scaling`
+//!
+//! Wall-clock timing (the default in `fib_benchmarks.rs`) is noisy for the
+//! log-n `matrix::fib_matrix_fast`/`matrix::fib_doubling` routines at small
+//! `n`, where scheduler jitter and clock-frequency scaling can dwarf the
+//! actual work done. This file re-runs the same two benchmark groups under a
+//! custom Criterion `Measurement` that counts retired hardware events
+//! instead, giving a deterministic, frequency-independent number to compare
+//! them by.
+//!
+//! Gated behind the `hw-counters` feature, since it needs the `perf-event`
+//! crate (Linux-only) and is only useful when you actually want cycle/
+//! instruction counts rather than nanoseconds.
+//!
+//! Run with: `cargo bench --bench hw_counter_benchmarks --features hw-counters`
+
+#[cfg(feature = "hw-counters")]
+mod hw_counters {
+    //! A Criterion [`Measurement`] backed by a hardware performance counter:
+    //! `perf-event` on Linux, falling back to a raw `rdtsc` cycle read
+    //! elsewhere (which can only count cycles, not retired instructions).
+
+    use criterion::measurement::{Measurement, ValueFormatter};
+    use criterion::Throughput;
+
+    #[cfg(target_os = "linux")]
+    use perf_event::events::Hardware;
+    #[cfg(target_os = "linux")]
+    use perf_event::Counter;
+
+    /// Which hardware event a [`HwCounters`] measurement accumulates
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum HwEvent {
+        Cycles,
+        Instructions,
+    }
+
+    /// A Criterion measurement reporting retired hardware-event counts
+    /// rather than nanoseconds
+    pub struct HwCounters {
+        event: HwEvent,
+        #[cfg(target_os = "linux")]
+        counter: std::cell::RefCell<Counter>,
+    }
+
+    impl HwCounters {
+        /// Count CPU cycles elapsed during each iteration
+        pub fn cycles() -> Self {
+            Self::new(HwEvent::Cycles)
+        }
+
+        /// Count retired instructions during each iteration (Linux only;
+        /// the `rdtsc` fallback has no instruction-count equivalent)
+        pub fn instructions() -> Self {
+            Self::new(HwEvent::Instructions)
+        }
+
+        #[cfg(target_os = "linux")]
+        fn new(event: HwEvent) -> Self {
+            let kind = match event {
+                HwEvent::Cycles => Hardware::CPU_CYCLES,
+                HwEvent::Instructions => Hardware::INSTRUCTIONS,
+            };
+            let counter = perf_event::Builder::new()
+                .kind(kind)
+                .build()
+                .expect(
+                    "failed to open a perf-event counter; \
+                     check /proc/sys/kernel/perf_event_paranoid or run as root",
+                );
+            Self {
+                event,
+                counter: std::cell::RefCell::new(counter),
+            }
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        fn new(event: HwEvent) -> Self {
+            Self { event }
+        }
+    }
+
+    impl Measurement for HwCounters {
+        type Intermediate = u64;
+        type Value = u64;
+
+        fn start(&self) -> Self::Intermediate {
+            #[cfg(target_os = "linux")]
+            {
+                let mut counter = self.counter.borrow_mut();
+                counter.reset().expect("failed to reset perf-event counter");
+                counter
+                    .enable()
+                    .expect("failed to enable perf-event counter");
+                0
+            }
+            #[cfg(not(target_os = "linux"))]
+            {
+                read_rdtsc()
+            }
+        }
+
+        fn end(&self, start: Self::Intermediate) -> Self::Value {
+            #[cfg(target_os = "linux")]
+            {
+                let mut counter = self.counter.borrow_mut();
+                counter
+                    .disable()
+                    .expect("failed to disable perf-event counter");
+                let _ = start;
+                counter.read().expect("failed to read perf-event counter")
+            }
+            #[cfg(not(target_os = "linux"))]
+            {
+                read_rdtsc().saturating_sub(start)
+            }
+        }
+
+        fn add(&self, v1: &Self::Value, v2: &Self::Value) -> Self::Value {
+            v1 + v2
+        }
+
+        fn zero(&self) -> Self::Value {
+            0
+        }
+
+        fn to_f64(&self, value: &Self::Value) -> f64 {
+            *value as f64
+        }
+
+        fn formatter(&self) -> &dyn ValueFormatter {
+            match self.event {
+                HwEvent::Cycles => &CYCLES_FORMATTER,
+                HwEvent::Instructions => &INSTRUCTIONS_FORMATTER,
+            }
+        }
+    }
+
+    /// Raw cycle-counter read (`rdtsc`) for non-Linux x86_64 targets; on
+    /// other non-Linux architectures it returns a constant 0. Less precise
+    /// than `perf-event` — no access to retired-instruction counts — but
+    /// needs no privileges.
+    #[cfg(not(target_os = "linux"))]
+    fn read_rdtsc() -> u64 {
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            core::arch::x86_64::_rdtsc()
+        }
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            0
+        }
+    }
+
+    /// Prints a raw event count suffixed with its unit, e.g. `"1234 cycles"`
+    struct CountFormatter {
+        unit: &'static str,
+    }
+
+    impl ValueFormatter for CountFormatter {
+        fn format_value(&self, value: f64) -> String {
+            format!("{:.0} {}", value, self.unit)
+        }
+
+        fn format_throughput(&self, throughput: &Throughput, value: f64) -> String {
+            match throughput {
+                Throughput::Elements(n) => format!("{:.2} {}/element", value / *n as f64, self.unit),
+                Throughput::Bytes(n) | Throughput::BytesDecimal(n) => {
+                    format!("{:.2} {}/byte", value / *n as f64, self.unit)
+                }
+            }
+        }
+
+        fn scale_values(&self, _typical_value: f64, _values: &mut [f64]) -> &'static str {
+            self.unit
+        }
+
+        fn scale_throughputs(
+            &self,
+            _typical_value: f64,
+            _throughput: &Throughput,
+            _values: &mut [f64],
+        ) -> &'static str {
+            self.unit
+        }
+
+        fn scale_for_machines(&self, _values: &mut [f64]) -> &'static str {
+            self.unit
+        }
+    }
+
+    static CYCLES_FORMATTER: CountFormatter = CountFormatter { unit: "cycles" };
+    static INSTRUCTIONS_FORMATTER: CountFormatter = CountFormatter {
+        unit: "instructions",
+    };
+}
+
+#[cfg(feature = "hw-counters")]
+mod benches_impl {
+    use super::hw_counters::HwCounters;
+    use criterion::measurement::Measurement;
+    use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+    use fib_core::{closed_form, iterative, matrix, recursive};
+
+    /// Same benchmark as `fib_benchmarks::complexity_comparison`, generic
+    /// over the measurement so it can run under either the default
+    /// wall-clock timer or a [`HwCounters`] instance.
+    fn complexity_comparison<M: Measurement>(c: &mut Criterion<M>) {
+        let mut group = c.benchmark_group("complexity_comparison");
+        group.sample_size(100);
+
+        for n in [10, 15, 20, 25].iter() {
+            if *n <= 25 {
+                group.bench_with_input(BenchmarkId::new("recursive", n), n, |b, &n| {
+                    b.iter(|| recursive::fib_recursive(black_box(n)))
+                });
+            }
+
+            group.bench_with_input(BenchmarkId::new("recursive_memo", n), n, |b, &n| {
+                b.iter(|| recursive::fib_recursive_memo(black_box(n)))
+            });
+
+            group.bench_with_input(BenchmarkId::new("iterative", n), n, |b, &n| {
+                b.iter(|| iterative::fib_iterative(black_box(n)))
+            });
+
+            group.bench_with_input(BenchmarkId::new("matrix", n), n, |b, &n| {
+                b.iter(|| matrix::fib_matrix_fast(black_box(n)))
+            });
+
+            group.bench_with_input(BenchmarkId::new("fast_doubling", n), n, |b, &n| {
+                b.iter(|| matrix::fib_doubling(black_box(n)))
+            });
+
+            group.bench_with_input(BenchmarkId::new("binet", n), n, |b, &n| {
+                b.iter(|| closed_form::fib_binet_f64(black_box(n)))
+            });
+        }
+
+        group.finish();
+    }
+
+    /// Same benchmark as `fib_benchmarks::large_n_scaling`, generic over the
+    /// measurement for the same reason as [`complexity_comparison`].
+    fn large_n_scaling<M: Measurement>(c: &mut Criterion<M>) {
+        let mut group = c.benchmark_group("large_n");
+        group.sample_size(50);
+
+        for n in [100, 500, 1000, 5000, 10000].iter() {
+            group.bench_with_input(BenchmarkId::new("iterative", n), n, |b, &n| {
+                b.iter(|| iterative::fib_iterative(black_box(n)))
+            });
+
+            group.bench_with_input(BenchmarkId::new("matrix", n), n, |b, &n| {
+                b.iter(|| matrix::fib_matrix_fast(black_box(n)))
+            });
+
+            group.bench_with_input(BenchmarkId::new("doubling", n), n, |b, &n| {
+                b.iter(|| matrix::fib_doubling(black_box(n)))
+            });
+        }
+
+        group.finish();
+    }
+
+    criterion_group! {
+        name = benches;
+        config = Criterion::default().with_measurement(HwCounters::instructions());
+        targets = complexity_comparison, large_n_scaling
+    }
+
+    criterion_main!(benches);
+}
+
+#[cfg(not(feature = "hw-counters"))]
+fn main() {
+    eprintln!(
+        "hw_counter_benchmarks requires the `hw-counters` feature: \
+         cargo bench --bench hw_counter_benchmarks --features hw-counters"
+    );
+}
@@ -12,6 +12,7 @@ pub struct TrackingAllocator {
     allocator: System,
     allocated_bytes: AtomicUsize,
     allocation_count: AtomicUsize,
+    peak_bytes: AtomicUsize,
 }
 
 impl Default for TrackingAllocator {
@@ -26,6 +27,7 @@ impl TrackingAllocator {
             allocator: System,
             allocated_bytes: AtomicUsize::new(0),
             allocation_count: AtomicUsize::new(0),
+            peak_bytes: AtomicUsize::new(0),
         }
     }
 
@@ -33,6 +35,15 @@ impl TrackingAllocator {
     pub fn reset(&self) {
         self.allocated_bytes.store(0, Ordering::SeqCst);
         self.allocation_count.store(0, Ordering::SeqCst);
+        self.peak_bytes.store(0, Ordering::SeqCst);
+    }
+
+    /// Reset only the high-water mark, leaving current usage and the
+    /// allocation count untouched. Useful for measuring the peak of one
+    /// call in a loop without losing the running allocation count between
+    /// iterations.
+    pub fn reset_peak(&self) {
+        self.peak_bytes.store(0, Ordering::SeqCst);
     }
 
     /// Get total bytes currently allocated (net)
@@ -51,14 +62,26 @@ impl TrackingAllocator {
     pub fn get_allocation_count(&self) -> usize {
         self.allocation_count.load(Ordering::SeqCst)
     }
+
+    /// Get the high-water-mark of bytes allocated since the last `reset()`
+    ///
+    /// Unlike `get_current_usage()`, this does not drop back down when memory
+    /// is freed, so it captures the true peak for allocate-then-free patterns
+    /// (e.g. the `RecursiveMemo` memo vector).
+    pub fn get_peak_usage(&self) -> usize {
+        self.peak_bytes.load(Ordering::SeqCst)
+    }
 }
 
 unsafe impl GlobalAlloc for TrackingAllocator {
     unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
         let ptr = self.allocator.alloc(layout);
         if !ptr.is_null() {
-            self.allocated_bytes
-                .fetch_add(layout.size(), Ordering::SeqCst);
+            let new_current = self
+                .allocated_bytes
+                .fetch_add(layout.size(), Ordering::SeqCst)
+                + layout.size();
+            self.peak_bytes.fetch_max(new_current, Ordering::SeqCst);
             self.allocation_count.fetch_add(1, Ordering::SeqCst);
         }
         ptr
@@ -86,6 +109,43 @@ mod tests {
         let allocator = TrackingAllocator::new();
         assert_eq!(allocator.get_current_usage(), 0);
         assert_eq!(allocator.get_allocation_count(), 0);
+        assert_eq!(allocator.get_peak_usage(), 0);
+    }
+
+    #[test]
+    fn test_peak_usage_survives_dealloc() {
+        let allocator = TrackingAllocator::new();
+        let layout = Layout::from_size_align(1024, 8).unwrap();
+
+        unsafe {
+            let ptr = allocator.alloc(layout);
+            assert_eq!(allocator.get_peak_usage(), 1024);
+
+            allocator.dealloc(ptr, layout);
+            // Current usage drops back to 0, but the peak is retained.
+            assert_eq!(allocator.get_current_usage(), 0);
+            assert_eq!(allocator.get_peak_usage(), 1024);
+        }
+    }
+
+    #[test]
+    fn test_peak_usage_tracks_high_water_mark() {
+        let allocator = TrackingAllocator::new();
+        let small = Layout::from_size_align(100, 4).unwrap();
+        let big = Layout::from_size_align(1000, 4).unwrap();
+
+        unsafe {
+            let p1 = allocator.alloc(big);
+            let p2 = allocator.alloc(small);
+            assert_eq!(allocator.get_peak_usage(), 1100);
+
+            allocator.dealloc(p1, big);
+            // Current usage shrank, but peak should not decrease.
+            assert_eq!(allocator.get_current_usage(), 100);
+            assert_eq!(allocator.get_peak_usage(), 1100);
+
+            allocator.dealloc(p2, small);
+        }
     }
 
     #[test]
@@ -119,6 +179,7 @@ mod tests {
             allocator.reset();
             assert_eq!(allocator.get_current_usage(), 0);
             assert_eq!(allocator.get_allocation_count(), 0);
+            assert_eq!(allocator.get_peak_usage(), 0);
 
             // Cleanup
             allocator.dealloc(ptr, layout);
@@ -128,4 +189,23 @@ mod tests {
             // unless we wanted to start fresh counting from that point.
         }
     }
+
+    #[test]
+    fn test_reset_peak_leaves_current_usage_and_count_untouched() {
+        let allocator = TrackingAllocator::new();
+        let layout = Layout::from_size_align(256, 8).unwrap();
+
+        unsafe {
+            let ptr = allocator.alloc(layout);
+            assert_eq!(allocator.get_peak_usage(), 256);
+
+            allocator.reset_peak();
+            assert_eq!(allocator.get_peak_usage(), 0);
+            // Unlike `reset()`, current usage and allocation count survive.
+            assert_eq!(allocator.get_current_usage(), 256);
+            assert_eq!(allocator.get_allocation_count(), 1);
+
+            allocator.dealloc(ptr, layout);
+        }
+    }
 }
@@ -6,6 +6,11 @@
 use num_bigint::BigUint;
 use num_traits::{One, Zero};
 
+#[cfg(feature = "std")]
+use std::vec::Vec;
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::vec::Vec;
+
 /// Iterative Fibonacci using BigUint
 ///
 /// # Complexity
@@ -28,6 +33,104 @@ pub fn fib_iterative_big(n: u64) -> BigUint {
     }
 }
 
+/// Unbounded iterator over Fibonacci numbers as `BigUint`
+///
+/// Identical `current`/`next` update rule to
+/// [`FibonacciIterator`](crate::iterative::FibonacciIterator), but never
+/// overflows since it grows the result with arbitrary precision instead of
+/// wrapping at `u128::MAX` (F(186)).
+///
+/// # Example
+/// ```
+/// use fib_core::bigint::FibonacciBigIterator;
+/// use num_bigint::BigUint;
+///
+/// let fibs: Vec<BigUint> = FibonacciBigIterator::new().take(10).collect();
+/// assert_eq!(fibs, vec![0u32, 1, 1, 2, 3, 5, 8, 13, 21, 34].into_iter().map(BigUint::from).collect::<Vec<_>>());
+/// ```
+pub struct FibonacciBigIterator {
+    current: BigUint,
+    next: BigUint,
+}
+
+impl FibonacciBigIterator {
+    pub fn new() -> Self {
+        Self {
+            current: Zero::zero(),
+            next: One::one(),
+        }
+    }
+}
+
+impl Default for FibonacciBigIterator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Iterator for FibonacciBigIterator {
+    type Item = BigUint;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.current.clone();
+        let new_next = &self.current + &self.next;
+        self.current = core::mem::replace(&mut self.next, new_next);
+        Some(result)
+    }
+}
+
+/// Fibonacci cache over `BigUint` that lazily extends on query
+///
+/// Like [`FibonacciCache`](crate::iterative::FibonacciCache), but backed by
+/// `Vec<BigUint>` and never capped at `u128::MAX`: [`get`](Self::get) grows
+/// the cache on demand instead of returning `None` past a fixed limit, so
+/// repeated queries against an ever-larger index amortize past computation
+/// rather than redoing it.
+///
+/// # Example
+/// ```
+/// use fib_core::bigint::GrowableFibonacciCache;
+/// use num_bigint::BigUint;
+///
+/// let mut cache = GrowableFibonacciCache::new();
+/// assert_eq!(*cache.get(10), BigUint::from(55u32));
+/// assert_eq!(*cache.get(200), fib_core::bigint::fib_matrix_big(200));
+/// ```
+pub struct GrowableFibonacciCache {
+    values: Vec<BigUint>,
+}
+
+impl GrowableFibonacciCache {
+    /// Create a new cache seeded with F(0) and F(1)
+    pub fn new() -> Self {
+        Self {
+            values: vec![Zero::zero(), One::one()],
+        }
+    }
+
+    /// Get F(n), extending the cache with any values not yet computed
+    pub fn get(&mut self, n: u64) -> &BigUint {
+        let n = n as usize;
+        while self.values.len() <= n {
+            let len = self.values.len();
+            let next = &self.values[len - 1] + &self.values[len - 2];
+            self.values.push(next);
+        }
+        &self.values[n]
+    }
+
+    /// The largest index currently cached without needing to extend
+    pub fn max_cached_n(&self) -> u64 {
+        (self.values.len() - 1) as u64
+    }
+}
+
+impl Default for GrowableFibonacciCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Matrix Fibonacci using BigUint
 ///
 /// # Complexity
@@ -56,6 +159,96 @@ pub fn fib_matrix_big(n: u64) -> BigUint {
     result[0][1].clone()
 }
 
+/// Windowed (sliding-window) matrix exponentiation for BigUint Fibonacci
+///
+/// `fib_matrix_big` performs one general 2x2 `BigUint` matrix multiply per
+/// set bit of `n`, on top of the squarings — and each multiply is eight
+/// expensive big-integer products. This precomputes the odd powers of the
+/// base matrix `B^1, B^3, B^5, ..., B^(2^w-1)` once, then scans `n`'s bits
+/// left-to-right in width-`w` windows: square `w` times per window, and
+/// multiply by the matching precomputed odd power whenever a window ends in
+/// a set bit. This cuts the number of general matrix multiplications from
+/// ~log2(n) to ~log2(n)/w, at the cost of `2^(w-1)` precomputed matrices.
+///
+/// Results are bit-identical to [`fib_matrix_big`].
+///
+/// # Example
+/// ```
+/// use fib_core::bigint::{fib_matrix_big, fib_matrix_big_windowed};
+///
+/// assert_eq!(fib_matrix_big_windowed(200, 4), fib_matrix_big(200));
+/// ```
+pub fn fib_matrix_big_windowed(n: u64, window: u32) -> BigUint {
+    if n == 0 {
+        return Zero::zero();
+    }
+    if n == 1 {
+        return One::one();
+    }
+
+    let base: [[BigUint; 2]; 2] = [[One::one(), One::one()], [One::one(), Zero::zero()]];
+    windowed_matrix_pow(&base, n, window)[0][1].clone()
+}
+
+/// Choose a sensible default window width based on the bit-length of `n`
+///
+/// Larger windows amortize the precomputation cost better for larger `n`.
+pub fn default_window_for(n: u64) -> u32 {
+    match n {
+        0..=4_095 => 4,
+        4_096..=999_999 => 5,
+        _ => 6,
+    }
+}
+
+fn windowed_matrix_pow(base: &[[BigUint; 2]; 2], n: u64, window: u32) -> [[BigUint; 2]; 2] {
+    let window = window.max(1);
+    let table_size = 1usize << (window - 1);
+
+    // odd_powers[i] = base^(2*i + 1)
+    let base_sq = mul_matrix_big(base, base);
+    let mut odd_powers: Vec<[[BigUint; 2]; 2]> = Vec::with_capacity(table_size);
+    odd_powers.push(base.clone());
+    for i in 1..table_size {
+        odd_powers.push(mul_matrix_big(&odd_powers[i - 1], &base_sq));
+    }
+
+    let bit_len = 64 - n.leading_zeros();
+    let bit_at = |i: u32| -> bool { (n >> i) & 1 == 1 };
+
+    let mut result: [[BigUint; 2]; 2] = [[One::one(), Zero::zero()], [Zero::zero(), One::one()]];
+
+    let mut i = bit_len as i64 - 1;
+    while i >= 0 {
+        if !bit_at(i as u32) {
+            result = mul_matrix_big(&result, &result);
+            i -= 1;
+            continue;
+        }
+
+        // Widen the window as far left as possible without exceeding `window`
+        // bits, then trim leading zero bits so it always ends (and starts) at a 1.
+        let mut l = (i - window as i64 + 1).max(0) as u32;
+        while !bit_at(l) {
+            l += 1;
+        }
+
+        for _ in 0..=(i as u32 - l) {
+            result = mul_matrix_big(&result, &result);
+        }
+
+        let mut d: u64 = 0;
+        for b in (l..=i as u32).rev() {
+            d = (d << 1) | (bit_at(b) as u64);
+        }
+
+        result = mul_matrix_big(&result, &odd_powers[((d - 1) / 2) as usize]);
+        i = l as i64 - 1;
+    }
+
+    result
+}
+
 fn mul_matrix_big(a: &[[BigUint; 2]; 2], b: &[[BigUint; 2]; 2]) -> [[BigUint; 2]; 2] {
     [
         [
@@ -69,6 +262,50 @@ fn mul_matrix_big(a: &[[BigUint; 2]; 2], b: &[[BigUint; 2]; 2]) -> [[BigUint; 2]
     ]
 }
 
+/// Fast-doubling Fibonacci over `BigUint`
+///
+/// Same doubling identity as [`matrix::fib_doubling`](crate::matrix::fib_doubling)
+/// (`F(2k) = F(k)(2F(k+1) - F(k))`, `F(2k+1) = F(k)^2 + F(k+1)^2`), but carried
+/// out over `BigUint` instead of `u128`, so it runs in O(log n) big-integer
+/// multiplications with no overflow ceiling. Three multiplications per level
+/// versus the eight big-integer products of each 2x2 matrix multiply in
+/// [`fib_matrix_big`] (one or two such multiplies per bit of n), so this is
+/// the preferred exact backend for unbounded n.
+///
+/// # Example
+/// ```
+/// use fib_core::bigint::fib_bigint;
+/// use num_bigint::BigUint;
+///
+/// assert_eq!(fib_bigint(10), BigUint::from(55u32));
+/// assert_eq!(fib_bigint(200), fib_core::bigint::fib_matrix_big(200));
+/// ```
+#[cfg(feature = "bigint")]
+pub fn fib_bigint(n: u64) -> BigUint {
+    fib_pair_big(n).0
+}
+
+/// Returns `(F(n), F(n+1))`, the pair the doubling recurrence is built on.
+#[cfg(feature = "bigint")]
+fn fib_pair_big(n: u64) -> (BigUint, BigUint) {
+    if n == 0 {
+        return (Zero::zero(), One::one());
+    }
+
+    let (f_k, f_k1) = fib_pair_big(n / 2);
+
+    let two_f_k1_minus_f_k = (&f_k1 * 2u32) - &f_k;
+    let f_2k = &f_k * two_f_k1_minus_f_k;
+    let f_2k1 = (&f_k * &f_k) + (&f_k1 * &f_k1);
+
+    if n & 1 == 0 {
+        (f_2k, f_2k1)
+    } else {
+        let f_2k_plus_1 = &f_2k + &f_2k1;
+        (f_2k1, f_2k_plus_1)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -88,6 +325,61 @@ mod tests {
         assert_eq!(fib_matrix_big(20), BigUint::from(6765u32));
     }
 
+    #[test]
+    fn test_fib_matrix_big_windowed_matches_unwindowed() {
+        for n in [0u64, 1, 2, 10, 50, 100, 200, 1000] {
+            for window in [1u32, 2, 3, 4, 5, 6] {
+                assert_eq!(
+                    fib_matrix_big_windowed(n, window),
+                    fib_matrix_big(n),
+                    "mismatch at n={}, window={}",
+                    n,
+                    window
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_default_window_for() {
+        assert_eq!(default_window_for(10), 4);
+        assert_eq!(default_window_for(10_000), 5);
+        assert_eq!(default_window_for(10_000_000), 6);
+    }
+
+    #[test]
+    fn test_fibonacci_big_iterator() {
+        let fibs: Vec<BigUint> = FibonacciBigIterator::new().take(10).collect();
+        let expected: Vec<BigUint> = [0u32, 1, 1, 2, 3, 5, 8, 13, 21, 34]
+            .into_iter()
+            .map(BigUint::from)
+            .collect();
+        assert_eq!(fibs, expected);
+    }
+
+    #[test]
+    fn test_fibonacci_big_iterator_past_u128() {
+        let f200 = FibonacciBigIterator::new().nth(200).unwrap();
+        assert_eq!(f200, fib_matrix_big(200));
+    }
+
+    #[test]
+    fn test_growable_fibonacci_cache() {
+        let mut cache = GrowableFibonacciCache::new();
+        assert_eq!(*cache.get(0), BigUint::zero());
+        assert_eq!(*cache.get(1), BigUint::one());
+        assert_eq!(*cache.get(10), BigUint::from(55u32));
+        assert_eq!(cache.max_cached_n(), 10);
+    }
+
+    #[test]
+    fn test_growable_fibonacci_cache_extends_past_u128() {
+        let mut cache = GrowableFibonacciCache::new();
+        assert_eq!(*cache.get(200), fib_matrix_big(200));
+        // Re-querying a smaller n after growing shouldn't recompute incorrectly.
+        assert_eq!(*cache.get(10), BigUint::from(55u32));
+    }
+
     #[test]
     fn test_large_fib() {
         // F(200) - verifies it works beyond u128
@@ -96,4 +388,21 @@ mod tests {
         let s = f200.to_string();
         assert_eq!(s, "280571172992510140037611932413038677189525");
     }
+
+    #[test]
+    #[cfg(feature = "bigint")]
+    fn test_fib_bigint_matches_fib_matrix_big() {
+        for n in [0u64, 1, 2, 10, 50, 100, 200, 1000] {
+            assert_eq!(fib_bigint(n), fib_matrix_big(n), "mismatch at n={}", n);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "bigint")]
+    fn test_fib_bigint_known_value() {
+        assert_eq!(
+            fib_bigint(200).to_string(),
+            "280571172992510140037611932413038677189525"
+        );
+    }
 }
@@ -97,6 +97,122 @@ pub fn fib_binet_scientific(n: u64) -> (f64, i32) {
     (mantissa, exponent)
 }
 
+/// Error returned by [`fib_binet_checked`] when floating-point error makes
+/// the nearest-integer rounding decision unsafe
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinetError {
+    /// The computed value landed within the certified error bound of a
+    /// rounding tie (`x.5`), so either integer could be the true F(n)
+    Ambiguous {
+        /// The Fibonacci index that triggered the ambiguity
+        n: u64,
+    },
+}
+
+impl core::fmt::Display for BinetError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            BinetError::Ambiguous { n } => write!(
+                f,
+                "Binet formula result for F({}) is within its certified error bound of a rounding tie",
+                n
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BinetError {}
+
+/// Binet's formula with a certified rounding-safety check, instead of
+/// blindly rounding and hoping
+///
+/// `phi^n / sqrt5` is computed in f64, together with two error bounds: the
+/// magnitude of the dropped `psi^n / sqrt5` term (always < 0.5, shrinking
+/// fast as n grows), and the accumulated rounding error from the `powi`
+/// chain and the division (each elementary f64 operation contributes at
+/// most 0.5 ulp, and `powi`'s binary-exponentiation chain takes roughly
+/// `2*log2(n) + 4` such operations). If the computed value's fractional
+/// part falls within that combined error band of `0.5` — meaning a
+/// different, equally justified floating-point evaluation could round the
+/// other way — this returns [`BinetError::Ambiguous`] instead of silently
+/// picking an integer that might be wrong.
+///
+/// # Example
+/// ```
+/// use fib_core::closed_form::fib_binet_checked;
+///
+/// assert_eq!(fib_binet_checked(10), Ok(55));
+/// assert_eq!(fib_binet_checked(50), Ok(12586269025));
+/// ```
+pub fn fib_binet_checked(n: u64) -> Result<u128, BinetError> {
+    if n <= 1 {
+        return Ok(n as u128);
+    }
+
+    let sqrt5 = 5.0_f64.sqrt();
+    let phi = (1.0 + sqrt5) / 2.0;
+    let psi = (1.0 - sqrt5) / 2.0;
+
+    let value = phi.powi(n as i32) / sqrt5;
+
+    if !value.is_finite() {
+        return Err(BinetError::Ambiguous { n });
+    }
+
+    // Magnitude of the term Binet's formula drops by approximating
+    // F(n) ≈ phi^n / sqrt5 instead of (phi^n - psi^n) / sqrt5.
+    let psi_term_bound = psi.abs().powi(n as i32) / sqrt5;
+
+    // `powi` squares roughly log2(n) times plus a handful of multiplies to
+    // assemble the bits of n; each elementary f64 op is within 0.5 ulp.
+    let op_count = 2.0 * (n.max(1) as f64).log2() + 4.0;
+    let rounding_error_bound = op_count * 0.5 * f64::EPSILON * value.abs();
+
+    let error_bound = psi_term_bound + rounding_error_bound;
+
+    let frac = value - value.floor();
+    let distance_to_tie = (frac - 0.5).abs();
+
+    if distance_to_tie <= error_bound {
+        return Err(BinetError::Ambiguous { n });
+    }
+
+    Ok(value.round() as u128)
+}
+
+/// Largest n for which F(n) is guaranteed to fit in a `u128`
+///
+/// Derived from `n*log2(φ) - log2(√5) < 128`: F(n) has roughly
+/// `n*log2(φ) - log2(√5)` bits, so the largest n keeping that strictly
+/// under 128 bits is `floor((128 + log2(√5)) / log2(φ)) = 186`
+/// (F(186) fits in a `u128`; F(187) overflows it).
+pub const BINET_U128_MAX_N: u64 = 186;
+
+/// Binet formula with rounding to nearest integer, refusing to silently
+/// wrap/saturate once F(n) no longer fits in a `u128`
+///
+/// Unlike [`fib_binet_rounded`], which truncates `as u128` regardless of
+/// whether F(n) actually fits, this returns `None` once `n` passes
+/// [`BINET_U128_MAX_N`], so callers composing closed-form results with
+/// native-integer code paths can branch cleanly instead of reasoning about
+/// `as u128` truncation.
+///
+/// # Example
+/// ```
+/// use fib_core::closed_form::fib_binet_rounded_checked;
+///
+/// assert_eq!(fib_binet_rounded_checked(10), Some(55));
+/// assert_eq!(fib_binet_rounded_checked(50), Some(12586269025));
+/// assert_eq!(fib_binet_rounded_checked(187), None);
+/// ```
+pub fn fib_binet_rounded_checked(n: u64) -> Option<u128> {
+    if n > BINET_U128_MAX_N {
+        return None;
+    }
+    Some(fib_binet_rounded(n))
+}
+
 /// Binet formula with rounding to nearest integer
 ///
 /// Since F(n) is always an integer, we can round the Binet result.
@@ -137,6 +253,293 @@ pub fn fib_binet_simplified(n: u64) -> f64 {
     (phi.powi(n as i32) / sqrt5).round()
 }
 
+/// Maximum n for which [`fib_binet_dd`] (double-double Binet) is exact
+pub const MAX_ACCURATE_N_DD: u64 = 150;
+
+/// A double-double float: an unevaluated sum `hi + lo` of two `f64`s giving
+/// roughly twice the precision (~31 significant decimal digits) of a plain
+/// `f64`. `lo` is always much smaller in magnitude than `hi` and holds the
+/// rounding error `hi` alone would have lost.
+#[derive(Clone, Copy, Debug)]
+struct DoubleDouble {
+    hi: f64,
+    lo: f64,
+}
+
+impl DoubleDouble {
+    fn from_f64(x: f64) -> Self {
+        Self { hi: x, lo: 0.0 }
+    }
+}
+
+/// Error-free transform for `a + b`: splits the rounding error of `a + b`
+/// into a correction term `e`, so `a + b == s + e` exactly.
+///
+/// This is the full (Knuth) 2Sum, which — unlike the cheaper "quick
+/// two-sum" — needs no `|a| >= |b|` ordering precondition; several call
+/// sites below (e.g. `dd_add` summing two unordered `DoubleDouble.hi`
+/// values) can't guarantee one.
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let bb = s - a;
+    let err_a = a - (s - bb);
+    let err_b = b - bb;
+    (s, err_a + err_b)
+}
+
+/// Error-free transform for `a * b`: splits the rounding error of `a * b`
+/// into a correction term `e`, so `a * b == p + e` exactly. Uses a fused
+/// multiply-add to recover the error in one extra operation instead of the
+/// multi-step splitting Dekker's original algorithm needs.
+fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    let e = a.mul_add(b, -p);
+    (p, e)
+}
+
+/// Double-double addition, built from [`two_sum`]
+fn dd_add(a: DoubleDouble, b: DoubleDouble) -> DoubleDouble {
+    let (s, e) = two_sum(a.hi, b.hi);
+    let e = e + a.lo + b.lo;
+    let (hi, lo) = two_sum(s, e);
+    DoubleDouble { hi, lo }
+}
+
+fn dd_neg(a: DoubleDouble) -> DoubleDouble {
+    DoubleDouble {
+        hi: -a.hi,
+        lo: -a.lo,
+    }
+}
+
+fn dd_sub(a: DoubleDouble, b: DoubleDouble) -> DoubleDouble {
+    dd_add(a, dd_neg(b))
+}
+
+/// Double-double multiplication, built from [`two_prod`]
+fn dd_mul(a: DoubleDouble, b: DoubleDouble) -> DoubleDouble {
+    let (p, e) = two_prod(a.hi, b.hi);
+    let e = e + a.hi * b.lo + a.lo * b.hi;
+    let (hi, lo) = two_sum(p, e);
+    DoubleDouble { hi, lo }
+}
+
+/// Multiply a double-double by a plain `f64` scalar
+fn dd_scale(a: DoubleDouble, scalar: f64) -> DoubleDouble {
+    dd_mul(a, DoubleDouble::from_f64(scalar))
+}
+
+/// Double-double division via iterative refinement of the `f64` quotient
+fn dd_div(a: DoubleDouble, b: DoubleDouble) -> DoubleDouble {
+    let q1 = a.hi / b.hi;
+    let r1 = dd_sub(a, dd_scale(b, q1));
+    let q2 = r1.hi / b.hi;
+    let r2 = dd_sub(r1, dd_scale(b, q2));
+    let q3 = r2.hi / b.hi;
+
+    let (s, e) = two_sum(q1, q2);
+    dd_add(DoubleDouble { hi: s, lo: e }, DoubleDouble::from_f64(q3))
+}
+
+/// Double-double square root of 5, refined from the `f64` approximation by
+/// Newton's method (`x -= (x*x - 5) / (2x)`) carried out in double-double
+/// arithmetic; each iteration roughly doubles the number of correct digits,
+/// so two iterations take the `f64` seed's ~16 digits well past the ~31
+/// digits a double-double can hold.
+fn dd_sqrt5() -> DoubleDouble {
+    let mut x = DoubleDouble::from_f64(5.0_f64.sqrt());
+
+    for _ in 0..2 {
+        let x_sq = dd_mul(x, x);
+        let diff = dd_sub(x_sq, DoubleDouble::from_f64(5.0));
+        let denom = dd_scale(x, 2.0);
+        x = dd_sub(x, dd_div(diff, denom));
+    }
+
+    x
+}
+
+/// Raise a double-double to an integer power via binary exponentiation
+fn dd_powi(mut base: DoubleDouble, mut exp: u64) -> DoubleDouble {
+    let mut result = DoubleDouble::from_f64(1.0);
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = dd_mul(result, base);
+        }
+        base = dd_mul(base, base);
+        exp >>= 1;
+    }
+
+    result
+}
+
+/// Binet's formula evaluated in double-double arithmetic
+fn fib_binet_dd_raw(n: u64) -> DoubleDouble {
+    let sqrt5 = dd_sqrt5();
+    let phi = dd_scale(dd_add(DoubleDouble::from_f64(1.0), sqrt5), 0.5);
+    let psi = dd_scale(dd_sub(DoubleDouble::from_f64(1.0), sqrt5), 0.5);
+
+    let numerator = dd_sub(dd_powi(phi, n), dd_powi(psi, n));
+    dd_div(numerator, sqrt5)
+}
+
+/// Fibonacci using Binet's formula in double-double (extended) precision
+///
+/// Identical formula to [`fib_binet_f64`], but `φ`, `ψ`, and `√5` are each
+/// carried as a [`DoubleDouble`] — an unevaluated `hi + lo` pair giving
+/// roughly twice the significant digits of a plain `f64` — which roughly
+/// doubles the range of n for which the rounded result is exact, from
+/// [`MAX_ACCURATE_N`] (≈78) to [`MAX_ACCURATE_N_DD`] (≈150).
+///
+/// # Example
+/// ```
+/// use fib_core::closed_form::fib_binet_dd;
+///
+/// assert_eq!(fib_binet_dd(10), 55);
+/// assert_eq!(fib_binet_dd(100), 354224848179261915075);
+/// ```
+pub fn fib_binet_dd(n: u64) -> u128 {
+    if n == 0 {
+        return 0;
+    }
+
+    let dd = fib_binet_dd_raw(n);
+    let hi_part = dd.hi.round() as i128;
+    let lo_part = dd.lo.round() as i128;
+    (hi_part + lo_part) as u128
+}
+
+/// Number of guard bits added on top of F(n)'s bit length when choosing the
+/// working precision for [`fib_binet_bignum`]
+#[cfg(feature = "mpfr")]
+const BIGNUM_GUARD_BITS: u32 = 32;
+
+/// Working precision, in bits, [`fib_binet_bignum`] uses to evaluate Binet's
+/// formula for a given `n`
+///
+/// F(n) has roughly `n * log2(phi) ≈ 0.6943 * n` significant bits; padding
+/// that estimate with [`BIGNUM_GUARD_BITS`] extra bits of precision is
+/// enough for the final rounding step to recover the exact integer for any
+/// n, since the accumulated error of evaluating the formula in MPFR shrinks
+/// much faster than one guard bit per evaluation.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "mpfr")]
+/// # {
+/// use fib_core::closed_form::binet_bignum_precision_bits;
+///
+/// assert!(binet_bignum_precision_bits(1000) > (0.6943 * 1000.0) as u32);
+/// # }
+/// ```
+#[cfg(feature = "mpfr")]
+pub fn binet_bignum_precision_bits(n: u64) -> u32 {
+    ((0.6943 * n as f64).ceil() as u32) + BIGNUM_GUARD_BITS
+}
+
+/// Arbitrary-precision Binet's formula, exact for any `n`
+///
+/// Defeats the `f64` precision ceiling ([`MAX_ACCURATE_N`]) by evaluating
+/// `(φ^n - ψ^n) / √5` with MPFR (via the `rug` crate) at a working precision
+/// of [`binet_bignum_precision_bits`] bits, then rounding to the nearest
+/// integer. With those guard bits in place the rounded result is provably
+/// exact, matching [`crate::matrix::fib_doubling`] bit-for-bit however large
+/// `n` gets.
+///
+/// Gated behind the `mpfr` feature since it pulls in `rug`, which links
+/// against the system GMP/MPFR/MPC libraries.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "mpfr")]
+/// # {
+/// use fib_core::closed_form::fib_binet_bignum;
+/// use rug::Integer;
+///
+/// assert_eq!(fib_binet_bignum(10), Integer::from(55));
+/// assert_eq!(fib_binet_bignum(100), Integer::from(354224848179261915075u128));
+/// # }
+/// ```
+#[cfg(feature = "mpfr")]
+pub fn fib_binet_bignum(n: u64) -> rug::Integer {
+    use rug::Float;
+
+    if n == 0 {
+        return rug::Integer::from(0);
+    }
+
+    let precision = binet_bignum_precision_bits(n);
+
+    let sqrt5 = Float::with_val(precision, 5u32).sqrt();
+    let phi = (Float::with_val(precision, 1u32) + &sqrt5) / Float::with_val(precision, 2u32);
+    let psi = (Float::with_val(precision, 1u32) - &sqrt5) / Float::with_val(precision, 2u32);
+
+    let numerator = phi.pow(n as u32) - psi.pow(n as u32);
+    let result = numerator / sqrt5;
+
+    result.round().to_integer().expect("finite Binet result")
+}
+
+/// Leading decimal digits of F(n), plus its total digit count, without
+/// materializing the full integer
+///
+/// Generalizes [`fib_binet_scientific`] (whose f64 mantissa is only good to
+/// ~15 digits and drops the ψ term entirely) to an arbitrary digit count by
+/// evaluating `log10(F(n)) ≈ n*log10(φ) - log10(√5)` at a working precision
+/// of `ceil(digits * log2(10)) + 32` bits via `rug::Float` (MPFR). The
+/// integer part of that (plus one) is the total digit count
+/// (`floor(n*log10(φ) - log10(√5)) + 1`); `10` raised to the fractional
+/// part gives the leading digits directly, with no need to ever form F(n)
+/// itself.
+///
+/// Gated behind the `mpfr` feature, same as [`fib_binet_bignum`].
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "mpfr")]
+/// # {
+/// use fib_core::closed_form::fib_binet_digits;
+///
+/// // F(100) = 354224848179261915075
+/// let (leading, total) = fib_binet_digits(100, 6);
+/// assert_eq!(leading, "354224");
+/// assert_eq!(total, 21);
+/// # }
+/// ```
+#[cfg(feature = "mpfr")]
+pub fn fib_binet_digits(n: u64, digits: usize) -> (String, i64) {
+    use rug::Float;
+
+    if n == 0 {
+        return ("0".to_string(), 1);
+    }
+
+    let precision = ((digits as f64) * core::f64::consts::LOG2_10).ceil() as u32 + 32;
+
+    let sqrt5 = Float::with_val(precision, 5u32).sqrt();
+    let log10_sqrt5 = sqrt5.clone().log10();
+    let phi = (Float::with_val(precision, 1u32) + sqrt5) / Float::with_val(precision, 2u32);
+    let log10_phi = phi.log10();
+
+    let log10_fib = Float::with_val(precision, n) * log10_phi - log10_sqrt5;
+
+    let floor_log10_fib = log10_fib.clone().floor();
+    let total_digits = floor_log10_fib
+        .to_i64_saturating()
+        .unwrap_or(i64::MAX)
+        + 1;
+
+    let frac = log10_fib - floor_log10_fib;
+    let mantissa = Float::with_val(precision, 10u32).pow(frac);
+
+    let mut digit_string = mantissa.to_string_radix(10, Some(digits + 2));
+    digit_string.retain(|c| c.is_ascii_digit());
+    digit_string.truncate(digits);
+
+    (digit_string, total_digits)
+}
+
 /// Analyze the error of Binet formula compared to exact value
 ///
 /// Returns (absolute_error, relative_error) tuple.
@@ -210,6 +613,67 @@ pub fn convergence_to_phi(n: u64) -> f64 {
     (ratio - PHI).abs()
 }
 
+/// Compute φ directly via the fixed-point recurrence `φ_{k+1} = 1 + 1/φ_k`,
+/// with no reference to Fibonacci numbers at all
+///
+/// Starts from `φ_0 = 1.0` and iterates until consecutive estimates differ
+/// by at most `tol`, or `max_iters` is reached. Since `x ↦ 1 + 1/x` is a
+/// contraction near φ, this converges linearly — each iteration roughly
+/// multiplies the error by `1/φ² ≈ 0.382` — so `tol = 1e-5` converges in
+/// about 14 steps.
+///
+/// # Returns
+/// `(estimate, iterations)` — the final φ estimate and how many iterations
+/// it took (capped at `max_iters`).
+///
+/// # Example
+/// ```
+/// use fib_core::closed_form::{phi_by_iteration, PHI};
+///
+/// let (phi, iters) = phi_by_iteration(1e-5, 100);
+/// assert!((phi - PHI).abs() < 1e-4);
+/// assert!(iters < 20);
+/// ```
+pub fn phi_by_iteration(tol: f64, max_iters: usize) -> (f64, usize) {
+    let mut phi = 1.0_f64;
+
+    for k in 0..max_iters {
+        let next = 1.0 + 1.0 / phi;
+        if (next - phi).abs() <= tol {
+            return (next, k + 1);
+        }
+        phi = next;
+    }
+
+    (phi, max_iters)
+}
+
+/// Evaluate the continued fraction `[1; 1, 1, 1, ...]` (golden ratio) to
+/// `depth` levels, working from the innermost level outward
+///
+/// The continued-fraction expansion of φ is all 1s: `φ = 1 + 1/(1 +
+/// 1/(1 + 1/(...)))`. Starting the innermost term at `1.0` and folding
+/// `1 + 1/x` outward `depth` times converges to φ at the same linear rate
+/// as [`phi_by_iteration`] (they're the same recurrence run in opposite
+/// directions).
+///
+/// # Example
+/// ```
+/// use fib_core::closed_form::{phi_continued_fraction, PHI};
+///
+/// let phi = phi_continued_fraction(20);
+/// assert!((phi - PHI).abs() < 1e-4);
+/// ```
+pub fn phi_continued_fraction(depth: usize) -> f64 {
+    let mut value = 1.0_f64;
+
+    for _ in 0..depth {
+        value = 1.0 + 1.0 / value;
+    }
+
+    value
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -280,4 +744,130 @@ mod tests {
         // Should be around 70-78
         assert!(limit >= 70 && limit <= 78);
     }
+
+    #[test]
+    fn test_fib_binet_dd_matches_plain_binet_where_both_are_exact() {
+        for n in 0..=MAX_ACCURATE_N {
+            assert_eq!(fib_binet_dd(n), fib_binet_rounded(n));
+        }
+    }
+
+    #[test]
+    fn test_fib_binet_dd_exact_past_plain_binet_limit() {
+        // f64 Binet already drifts off by n = 90; the double-double version
+        // should still be exact here.
+        assert_eq!(fib_binet_dd(90), 2880067194370816120);
+        assert_eq!(fib_binet_dd(100), 354224848179261915075);
+        assert_eq!(fib_binet_dd(130), 659034621587630041982498215);
+    }
+
+    #[test]
+    fn test_fib_binet_dd_exact_near_max_accurate_n_dd() {
+        assert_eq!(
+            fib_binet_dd(MAX_ACCURATE_N_DD),
+            9969216677189303386214405760200
+        );
+    }
+
+    #[cfg(feature = "mpfr")]
+    #[test]
+    fn test_fib_binet_bignum_matches_iterative() {
+        use rug::Integer;
+
+        for n in [0u64, 1, 10, 50, 100, 200, 500] {
+            let expected = Integer::from_str_radix(&crate::bigint::fib_iterative_big(n).to_string(), 10)
+                .unwrap();
+            assert_eq!(fib_binet_bignum(n), expected, "mismatch at n={}", n);
+        }
+    }
+
+    #[cfg(feature = "mpfr")]
+    #[test]
+    fn test_binet_bignum_precision_grows_with_n() {
+        assert!(binet_bignum_precision_bits(1000) > binet_bignum_precision_bits(100));
+    }
+
+    #[cfg(feature = "mpfr")]
+    #[test]
+    fn test_fib_binet_digits_matches_known_value() {
+        let (leading, total) = fib_binet_digits(100, 6);
+        assert_eq!(leading, "354224");
+        assert_eq!(total, 21);
+    }
+
+    #[cfg(feature = "mpfr")]
+    #[test]
+    fn test_fib_binet_digits_matches_exact_string_for_moderate_n() {
+        let exact = crate::bigint::fib_iterative_big(500).to_string();
+        let (leading, total) = fib_binet_digits(500, 15);
+        assert_eq!(total as usize, exact.len());
+        assert_eq!(leading, exact[..15]);
+    }
+
+    #[test]
+    fn test_fib_binet_checked_matches_exact_for_small_n() {
+        for n in 0u64..=70 {
+            let expected = crate::iterative::fib_iterative(n);
+            assert_eq!(fib_binet_checked(n), Ok(expected), "mismatch at n={}", n);
+        }
+    }
+
+    #[test]
+    fn test_fib_binet_checked_eventually_refuses_to_guess() {
+        // Far enough past MAX_ACCURATE_N that f64 precision is exhausted,
+        // the certified check should refuse rather than silently round.
+        assert!(fib_binet_checked(400).is_err());
+    }
+
+    #[test]
+    fn test_fib_binet_rounded_checked_within_range() {
+        assert_eq!(fib_binet_rounded_checked(10), Some(55));
+        assert_eq!(
+            fib_binet_rounded_checked(BINET_U128_MAX_N),
+            Some(fib_binet_rounded(BINET_U128_MAX_N))
+        );
+    }
+
+    #[test]
+    fn test_fib_binet_rounded_checked_past_u128_ceiling() {
+        assert_eq!(fib_binet_rounded_checked(BINET_U128_MAX_N + 1), None);
+        assert_eq!(fib_binet_rounded_checked(1000), None);
+    }
+
+    #[test]
+    fn test_phi_by_iteration_converges_to_phi() {
+        let (phi, iters) = phi_by_iteration(1e-5, 100);
+        assert!((phi - PHI).abs() < 1e-4);
+        assert!(iters < 20);
+    }
+
+    #[test]
+    fn test_phi_by_iteration_respects_max_iters() {
+        let (_, iters) = phi_by_iteration(1e-15, 3);
+        assert_eq!(iters, 3);
+    }
+
+    #[test]
+    fn test_phi_continued_fraction_converges_to_phi() {
+        let phi = phi_continued_fraction(30);
+        assert!((phi - PHI).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_phi_continued_fraction_matches_iteration() {
+        let (phi_iter, _) = phi_by_iteration(1e-10, 100);
+        let phi_cf = phi_continued_fraction(40);
+        assert!((phi_iter - phi_cf).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_fib_binet_checked_never_returns_a_wrong_value() {
+        // Every `Ok` result, across the range F(n) still fits in u128 (up
+        // to F(186)), must equal the exact value.
+        for n in 0u64..=186 {
+            if let Ok(value) = fib_binet_checked(n) {
+                assert_eq!(value, crate::iterative::fib_iterative(n), "mismatch at n={}", n);
+            }
+        }
+    }
 }
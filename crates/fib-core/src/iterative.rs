@@ -7,6 +7,19 @@
 //! - Time: O(n)
 //! - Space: O(1)
 
+// `fib_iterative`, `fib_iterative_branchless`, and `FibonacciIterator` need no
+// heap and compile in bare `no_std` (neither `std` nor `alloc`). The
+// `Vec`-backed batch/cache APIs below need one of the two and are gated
+// accordingly.
+#[cfg(feature = "std")]
+use std::vec::Vec;
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::vec;
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::vec;
+
 /// Standard iterative Fibonacci
 ///
 /// # Complexity
@@ -38,6 +51,36 @@ pub fn fib_iterative(n: u64) -> u128 {
     }
 }
 
+/// Overflow-checked iterative Fibonacci
+///
+/// Identical to [`fib_iterative`] but uses checked addition, so instead of
+/// panicking (debug) or silently wrapping (release) once the result exceeds
+/// `u128::MAX` (around F(186)), it returns the smallest Fibonacci index at
+/// which the overflow occurred.
+///
+/// # Example
+/// ```
+/// use fib_core::iterative::fib_iterative_checked;
+///
+/// assert_eq!(fib_iterative_checked(100), Ok(354224848179261915075));
+/// assert_eq!(fib_iterative_checked(187), Err(187));
+/// ```
+pub fn fib_iterative_checked(n: u64) -> Result<u128, u64> {
+    match n {
+        0 => Ok(0),
+        1 => Ok(1),
+        _ => {
+            let (mut a, mut b) = (0u128, 1u128);
+            for i in 2..=n {
+                let temp = a.checked_add(b).ok_or(i)?;
+                a = b;
+                b = temp;
+            }
+            Ok(b)
+        }
+    }
+}
+
 /// Branchless iterative Fibonacci for CPU pipeline optimization
 ///
 /// This version avoids conditional branches in the main loop,
@@ -79,6 +122,7 @@ pub fn fib_iterative_branchless(n: u64) -> u128 {
 ///
 /// assert_eq!(results, vec![5, 55, 610, 6765]);
 /// ```
+#[cfg(any(feature = "std", feature = "alloc"))]
 pub fn fib_iterative_batch(ns: &[u64]) -> Vec<u128> {
     if ns.is_empty() {
         return Vec::new();
@@ -114,6 +158,25 @@ pub fn fib_iterative_batch(ns: &[u64]) -> Vec<u128> {
     results
 }
 
+/// Batch calculation with per-element overflow detection
+///
+/// Like [`fib_iterative_batch`], but each slot is `None` instead of a wrapped
+/// or panicking value once F(n) exceeds `u128::MAX` (n ≥ 187).
+///
+/// # Example
+/// ```
+/// use fib_core::iterative::fib_iterative_batch_checked;
+///
+/// let ns = vec![10, 187, 20];
+/// let results = fib_iterative_batch_checked(&ns);
+///
+/// assert_eq!(results, vec![Some(55), None, Some(6765)]);
+/// ```
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub fn fib_iterative_batch_checked(ns: &[u64]) -> Vec<Option<u128>> {
+    ns.iter().map(|&n| fib_iterative_checked(n).ok()).collect()
+}
+
 /// Calculate Fibonacci with a maximum n cache for repeated queries
 ///
 /// Pre-computes all Fibonacci numbers up to max_n for O(1) lookups.
@@ -126,10 +189,12 @@ pub fn fib_iterative_batch(ns: &[u64]) -> Vec<u128> {
 /// assert_eq!(cache.get(50), Some(12586269025));
 /// assert_eq!(cache.get(101), None);  // Beyond cache limit
 /// ```
+#[cfg(any(feature = "std", feature = "alloc"))]
 pub struct FibonacciCache {
     values: Vec<u128>,
 }
 
+#[cfg(any(feature = "std", feature = "alloc"))]
 impl FibonacciCache {
     /// Create a new cache with all Fibonacci numbers up to n
     pub fn new(max_n: u64) -> Self {
@@ -222,6 +287,28 @@ mod tests {
         assert_eq!(fib_iterative(50), 12586269025);
     }
 
+    #[test]
+    fn test_fib_iterative_checked_fits() {
+        assert_eq!(fib_iterative_checked(0), Ok(0));
+        assert_eq!(fib_iterative_checked(10), Ok(55));
+        assert_eq!(fib_iterative_checked(186), Ok(fib_iterative(186)));
+    }
+
+    #[test]
+    fn test_fib_iterative_checked_overflow() {
+        // F(187) is the first value that doesn't fit in u128.
+        assert_eq!(fib_iterative_checked(187), Err(187));
+        assert_eq!(fib_iterative_checked(500), Err(187));
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    fn test_batch_calculation_checked() {
+        let ns = vec![10, 187, 20, 500];
+        let results = fib_iterative_batch_checked(&ns);
+        assert_eq!(results, vec![Some(55), None, Some(6765), None]);
+    }
+
     #[test]
     fn test_branchless_matches_standard() {
         for n in 0..100 {
@@ -235,6 +322,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(any(feature = "std", feature = "alloc"))]
     fn test_batch_calculation() {
         let ns = vec![0, 1, 5, 10, 20];
         let results = fib_iterative_batch(&ns);
@@ -242,6 +330,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(any(feature = "std", feature = "alloc"))]
     fn test_batch_calculation_unsorted_duplicates() {
         let ns = vec![10, 5, 10, 0, 1];
         let results = fib_iterative_batch(&ns);
@@ -250,6 +339,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(any(feature = "std", feature = "alloc"))]
     fn test_fibonacci_cache() {
         let cache = FibonacciCache::new(100);
         assert_eq!(cache.get(0), Some(0));
@@ -275,6 +365,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(any(feature = "std", feature = "alloc"))]
     fn test_fibonacci_cache_limit() {
         let cache = FibonacciCache::new(10);
         assert_eq!(cache.max_n(), 10);
@@ -16,6 +16,26 @@
 //! ## BigInt Support
 //!
 //! For calculations exceeding `u128::MAX` (F(186)), use `calculate_bigint` or the specific BigInt implementations.
+//! The `bigint` cargo feature additionally enables [`bigint::fib_bigint`], a
+//! fast-doubling `BigUint` backend (same recurrence as `matrix::fib_doubling`)
+//! for unbounded, exact n.
+//!
+//! ## `no_std` Support
+//!
+//! This crate builds under `#![no_std]` when the default `std` feature is
+//! disabled, and supports a separate `alloc` feature for heap access without
+//! a full `std` (pulling in `extern crate alloc`). The plain integer
+//! algorithms (`fib_iterative`, `fib_iterative_branchless`,
+//! `fib_recursive`, `fib_recursive_acc`, `matrix`) need no heap at all and
+//! compile under bare `no_std`. The `Vec`-backed APIs (`fib_iterative_batch`,
+//! `FibonacciCache`, `fib_recursive_memo`, `FibMethod::RecursiveMemo`) are
+//! gated behind `any(feature = "std", feature = "alloc")`; `bigint`,
+//! `calculate_bigint`, and `modular` always need `alloc` for `BigUint` (via
+//! `num-bigint`'s own `alloc` support), so enabling either `std` or `alloc`
+//! is required to use them. `closed_form` (the Binet
+//! formula, and the `FibMethod::Binet` variant) and the allocator-tracking
+//! `allocator`/`memory` modules rely on floating-point transcendental
+//! functions and `std::alloc::System` respectively, so they remain `std`-only.
 //!
 //! ## Example
 //!
@@ -34,25 +54,91 @@
 //! // 280571172992510140037611932413038677189525
 //! ```
 
+#![cfg_attr(not(feature = "std"), no_std)]
+// The `allocator` module (std-only, excluded below) is the sole user of
+// `unsafe` in this crate, for its `unsafe impl GlobalAlloc`. `no_std` builds
+// never compile that module, so it's safe to forbid unsafe code crate-wide
+// in that configuration; the `std` build keeps it permitted for `allocator`.
+#![cfg_attr(not(feature = "std"), forbid(unsafe_code))]
+
+// `BigUint` and the other heap-backed types (`Vec`, `String`) are needed with
+// or without `std`; under `no_std` they come from `alloc` instead.
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+// Tests always run under the standard test harness, so let test code use
+// `std` (e.g. `std::time::Instant` in `simd`'s benchmarks) regardless of
+// whether the crate itself is built `no_std`.
+#[cfg(test)]
+extern crate std;
+
+// `allocator`/`memory` instrument the global allocator via `std::alloc::System`,
+// and `closed_form`'s Binet formula needs `f64::sqrt`/`powf`, neither of which
+// exist without `std` (no `libm` dependency here) — both are `std`-only.
+#[cfg(feature = "std")]
 pub mod allocator;
 pub mod bigint;
+#[cfg(feature = "std")]
 pub mod closed_form;
 pub mod iterative;
 pub mod matrix;
+#[cfg(feature = "std")]
 pub mod memory;
+pub mod modular;
+pub mod number_theory;
 pub mod recursive;
 
-#[cfg(feature = "simd")]
+// SIMD batch calculation is a std-only optimization (runtime feature
+// detection and its `Vec`-returning API aren't part of the no_std surface).
+#[cfg(all(feature = "simd", feature = "std"))]
 pub mod simd;
 
 // Re-export main functions for convenience
 pub use bigint::{fib_iterative_big, fib_matrix_big};
+#[cfg(feature = "bigint")]
+pub use bigint::fib_bigint;
+#[cfg(feature = "std")]
 pub use closed_form::{binet_error_analysis, fib_binet_f64};
-pub use iterative::{fib_iterative, fib_iterative_batch, fib_iterative_branchless};
+pub use iterative::{fib_iterative, fib_iterative_branchless};
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use iterative::fib_iterative_batch;
 pub use matrix::{fib_doubling, fib_matrix_fast, fib_matrix_modulo};
-pub use recursive::{fib_recursive, fib_recursive_memo};
+pub use recursive::{fib_recursive, fib_recursive_acc};
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use recursive::fib_recursive_memo;
+
+/// Error returned by [`FibMethod::try_calculate`] when the result would
+/// overflow `u128`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FibOverflow {
+    /// The smallest Fibonacci index at which the `u128` computation overflowed
+    pub overflowed_at: u64,
+}
 
-#[cfg(feature = "simd")]
+impl core::fmt::Display for FibOverflow {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Fibonacci calculation overflowed u128 at F({})",
+            self.overflowed_at
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FibOverflow {}
+
+/// Result of [`FibMethod::calculate_auto`]: a `u128` when it fits, or a
+/// `BigUint` fallback when it doesn't
+#[derive(Debug, Clone)]
+pub enum FibResult {
+    /// The value fit in a `u128`
+    Exact(u128),
+    /// The value overflowed `u128` and was recomputed with arbitrary precision
+    BigUint(num_bigint::BigUint),
+}
+
+#[cfg(all(feature = "simd", feature = "std"))]
 pub use simd::{fib_simd_batch, SimdBatchCalculator, SimdFeatures};
 
 /// Enum representing available Fibonacci algorithms
@@ -61,7 +147,10 @@ pub enum FibMethod {
     /// Naive recursive - O(2^n)
     Recursive,
     /// Recursive with memoization - O(n)
+    #[cfg(any(feature = "std", feature = "alloc"))]
     RecursiveMemo,
+    /// Tail-recursive accumulator - O(n) time, O(1) heap allocation
+    RecursiveAcc,
     /// Iterative - O(n)
     Iterative,
     /// Iterative branchless - O(n)
@@ -71,6 +160,7 @@ pub enum FibMethod {
     /// Fast doubling - O(log n)
     FastDoubling,
     /// Binet formula - O(1) with precision limits
+    #[cfg(feature = "std")]
     Binet,
 }
 
@@ -89,15 +179,95 @@ impl FibMethod {
     pub fn calculate(&self, n: u64) -> u128 {
         match self {
             FibMethod::Recursive => fib_recursive(n),
+            #[cfg(any(feature = "std", feature = "alloc"))]
             FibMethod::RecursiveMemo => fib_recursive_memo(n),
+            FibMethod::RecursiveAcc => fib_recursive_acc(n),
             FibMethod::Iterative => fib_iterative(n),
             FibMethod::IterativeBranchless => fib_iterative_branchless(n),
             FibMethod::Matrix => fib_matrix_fast(n),
             FibMethod::FastDoubling => fib_doubling(n),
+            #[cfg(feature = "std")]
             FibMethod::Binet => fib_binet_f64(n) as u128,
         }
     }
 
+    /// Calculate Fibonacci, detecting `u128` overflow instead of
+    /// panicking/wrapping
+    ///
+    /// # Arguments
+    /// * `n` - The Fibonacci index to calculate
+    ///
+    /// # Returns
+    /// `Ok(value)` if the result fits in a `u128`, otherwise
+    /// `Err(FibOverflow)` reporting the smallest index that overflowed.
+    ///
+    /// # Example
+    /// ```
+    /// use fib_core::FibMethod;
+    ///
+    /// assert_eq!(FibMethod::Iterative.try_calculate(100), Ok(354224848179261915075));
+    /// assert!(FibMethod::Iterative.try_calculate(500).is_err());
+    /// ```
+    pub fn try_calculate(&self, n: u64) -> Result<u128, FibOverflow> {
+        let result = match self {
+            FibMethod::Recursive | FibMethod::RecursiveAcc => iterative::fib_iterative_checked(n),
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            FibMethod::RecursiveMemo => iterative::fib_iterative_checked(n),
+            FibMethod::Iterative | FibMethod::IterativeBranchless => {
+                iterative::fib_iterative_checked(n)
+            }
+            FibMethod::Matrix => {
+                matrix::fib_matrix_fast_checked(n).map_err(|_| matrix::MAX_EXACT_N + 1)
+            }
+            FibMethod::FastDoubling => {
+                matrix::fib_doubling_checked(n).map_err(|_| matrix::MAX_EXACT_N + 1)
+            }
+            #[cfg(feature = "std")]
+            FibMethod::Binet if n > matrix::MAX_EXACT_N => Err(matrix::MAX_EXACT_N + 1),
+            #[cfg(feature = "std")]
+            FibMethod::Binet => Ok(self.calculate(n)),
+        };
+
+        result.map_err(|overflowed_at| FibOverflow { overflowed_at })
+    }
+
+    /// Calculate Fibonacci, returning `None` on `u128` overflow
+    ///
+    /// A terser [`try_calculate`](Self::try_calculate) for callers who only
+    /// care whether the value fit, not the overflowing index — mirrors the
+    /// table-bounded `mem_fibonacci(n) -> Option<u128>` style some Fibonacci
+    /// crates expose, where `n` past the representable bound just yields
+    /// `None`.
+    ///
+    /// # Example
+    /// ```
+    /// use fib_core::FibMethod;
+    ///
+    /// assert_eq!(FibMethod::Iterative.calculate_checked(100), Some(354224848179261915075));
+    /// assert_eq!(FibMethod::Iterative.calculate_checked(500), None);
+    /// ```
+    pub fn calculate_checked(&self, n: u64) -> Option<u128> {
+        self.try_calculate(n).ok()
+    }
+
+    /// Calculate Fibonacci, automatically promoting to `BigUint` on overflow
+    ///
+    /// # Example
+    /// ```
+    /// use fib_core::{FibMethod, FibResult};
+    ///
+    /// match FibMethod::Iterative.calculate_auto(500) {
+    ///     FibResult::Exact(_) => unreachable!("F(500) overflows u128"),
+    ///     FibResult::BigUint(big) => assert!(big.to_string().len() > 38),
+    /// }
+    /// ```
+    pub fn calculate_auto(&self, n: u64) -> FibResult {
+        match self.try_calculate(n) {
+            Ok(value) => FibResult::Exact(value),
+            Err(_) => FibResult::BigUint(self.calculate_bigint(n)),
+        }
+    }
+
     /// Calculate Fibonacci using BigUint for arbitrary precision
     ///
     /// # Arguments
@@ -108,13 +278,18 @@ impl FibMethod {
     pub fn calculate_bigint(&self, n: u64) -> num_bigint::BigUint {
         match self {
             FibMethod::Iterative | FibMethod::IterativeBranchless => fib_iterative_big(n),
-            FibMethod::Matrix | FibMethod::FastDoubling => fib_matrix_big(n),
+            FibMethod::Matrix | FibMethod::FastDoubling => {
+                bigint::fib_matrix_big_windowed(n, bigint::default_window_for(n))
+            }
             // For others, fall back to calculate() and convert, or error if too big?
             // For safety, we use iterative big for any method that doesn't natively support it
             // if n is large, otherwise we can cast.
             // But since this is a BigInt method, users expect it to work for large n.
             // So we default to Matrix BigInt for "fast" methods and Iterative Big for others.
-            FibMethod::Recursive | FibMethod::RecursiveMemo => fib_iterative_big(n),
+            FibMethod::Recursive | FibMethod::RecursiveAcc => fib_iterative_big(n),
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            FibMethod::RecursiveMemo => fib_iterative_big(n),
+            #[cfg(feature = "std")]
             FibMethod::Binet => {
                 // Binet is approximate, but let's try to be consistent
                 num_bigint::BigUint::from(self.calculate(n))
@@ -122,15 +297,37 @@ impl FibMethod {
         }
     }
 
+    /// Calculate F(n) mod m for an arbitrary-size `BigUint` modulus
+    ///
+    /// Every method converges on the same matrix-exponentiation-mod-m
+    /// computation ([`modular::fib_mod`]), reducing `n` modulo the Pisano
+    /// period of `m` first so astronomically large indices are as cheap to
+    /// evaluate as small ones.
+    ///
+    /// # Example
+    /// ```
+    /// use fib_core::FibMethod;
+    /// use num_bigint::BigUint;
+    ///
+    /// let m = BigUint::from(1_000_000_007u32);
+    /// assert_eq!(FibMethod::Matrix.calculate_mod(1000, &m), BigUint::from(517691607u32));
+    /// ```
+    pub fn calculate_mod(&self, n: u64, m: &num_bigint::BigUint) -> num_bigint::BigUint {
+        modular::fib_mod(n, m)
+    }
+
     /// Get the name of the method
     pub fn name(&self) -> &'static str {
         match self {
             FibMethod::Recursive => "recursive",
+            #[cfg(any(feature = "std", feature = "alloc"))]
             FibMethod::RecursiveMemo => "recursive_memo",
+            FibMethod::RecursiveAcc => "recursive_acc",
             FibMethod::Iterative => "iterative",
             FibMethod::IterativeBranchless => "iterative_branchless",
             FibMethod::Matrix => "matrix",
             FibMethod::FastDoubling => "fast_doubling",
+            #[cfg(feature = "std")]
             FibMethod::Binet => "binet",
         }
     }
@@ -139,11 +336,14 @@ impl FibMethod {
     pub fn time_complexity(&self) -> &'static str {
         match self {
             FibMethod::Recursive => "O(2^n)",
+            #[cfg(any(feature = "std", feature = "alloc"))]
             FibMethod::RecursiveMemo => "O(n)",
+            FibMethod::RecursiveAcc => "O(n)",
             FibMethod::Iterative => "O(n)",
             FibMethod::IterativeBranchless => "O(n)",
             FibMethod::Matrix => "O(log n)",
             FibMethod::FastDoubling => "O(log n)",
+            #[cfg(feature = "std")]
             FibMethod::Binet => "O(1)",
         }
     }
@@ -152,27 +352,38 @@ impl FibMethod {
     pub fn space_complexity(&self) -> &'static str {
         match self {
             FibMethod::Recursive => "O(n)",
+            #[cfg(any(feature = "std", feature = "alloc"))]
             FibMethod::RecursiveMemo => "O(n)",
+            FibMethod::RecursiveAcc => "O(1)",
             FibMethod::Iterative => "O(1)",
             FibMethod::IterativeBranchless => "O(1)",
             FibMethod::Matrix => "O(1)",
             FibMethod::FastDoubling => "O(log n)",
+            #[cfg(feature = "std")]
             FibMethod::Binet => "O(1)",
         }
     }
 }
 
-impl std::str::FromStr for FibMethod {
+#[cfg(feature = "std")]
+use std::{format, string::String};
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String};
+
+impl core::str::FromStr for FibMethod {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "recursive" => Ok(FibMethod::Recursive),
+            #[cfg(any(feature = "std", feature = "alloc"))]
             "recursive_memo" | "memo" => Ok(FibMethod::RecursiveMemo),
+            "recursive_acc" | "acc" => Ok(FibMethod::RecursiveAcc),
             "iterative" => Ok(FibMethod::Iterative),
             "iterative_branchless" | "branchless" => Ok(FibMethod::IterativeBranchless),
             "matrix" => Ok(FibMethod::Matrix),
             "fast_doubling" | "doubling" => Ok(FibMethod::FastDoubling),
+            #[cfg(feature = "std")]
             "binet" => Ok(FibMethod::Binet),
             _ => Err(format!("Unknown method: {}", s)),
         }
@@ -210,6 +421,13 @@ mod tests {
                 "recursive_memo failed at n={}",
                 n
             );
+
+            assert_eq!(
+                fib_recursive_acc(n),
+                *expected,
+                "recursive_acc failed at n={}",
+                n
+            );
         }
     }
 
@@ -230,6 +448,47 @@ mod tests {
         assert_eq!(method.time_complexity(), "O(n)");
     }
 
+    #[test]
+    fn test_try_calculate_fits() {
+        assert_eq!(FibMethod::Iterative.try_calculate(100), Ok(fib_iterative(100)));
+        assert_eq!(FibMethod::Matrix.try_calculate(100), Ok(fib_iterative(100)));
+    }
+
+    #[test]
+    fn test_try_calculate_overflow() {
+        let err = FibMethod::Iterative.try_calculate(500).unwrap_err();
+        assert_eq!(err.overflowed_at, 187);
+        assert!(FibMethod::Matrix.try_calculate(500).is_err());
+        assert!(FibMethod::FastDoubling.try_calculate(500).is_err());
+    }
+
+    #[test]
+    fn test_calculate_checked_fits_and_overflows() {
+        assert_eq!(
+            FibMethod::Iterative.calculate_checked(100),
+            Some(fib_iterative(100))
+        );
+        assert_eq!(FibMethod::Iterative.calculate_checked(500), None);
+    }
+
+    #[test]
+    fn test_calculate_auto_promotes_to_biguint() {
+        match FibMethod::Iterative.calculate_auto(500) {
+            FibResult::Exact(_) => panic!("F(500) should overflow u128"),
+            FibResult::BigUint(big) => {
+                assert_eq!(big, FibMethod::Iterative.calculate_bigint(500));
+            }
+        }
+    }
+
+    #[test]
+    fn test_calculate_auto_stays_exact_for_small_n() {
+        match FibMethod::Iterative.calculate_auto(50) {
+            FibResult::Exact(v) => assert_eq!(v, 12586269025),
+            FibResult::BigUint(_) => panic!("F(50) should fit in u128"),
+        }
+    }
+
     #[test]
     fn test_fib_method_from_str() {
         use std::str::FromStr;
@@ -268,6 +527,7 @@ mod tests {
         let methods = [
             FibMethod::Recursive,
             FibMethod::RecursiveMemo,
+            FibMethod::RecursiveAcc,
             FibMethod::Iterative,
             FibMethod::IterativeBranchless,
             FibMethod::Matrix,
@@ -14,7 +14,11 @@
 //! Using fast exponentiation (repeated squaring), we can compute the
 //! matrix power in O(log n) matrix multiplications.
 
-use std::ops::Mul;
+use core::ops::Mul;
+
+/// Largest n for which F(n) still fits in a `u128`; F(187) is the first
+/// value to overflow.
+pub const MAX_EXACT_N: u64 = 186;
 
 /// 2x2 Matrix structure for Fibonacci calculation
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -110,12 +114,123 @@ pub fn fib_matrix_fast(n: u64) -> u128 {
     result.get(0, 1) // F(n)
 }
 
+/// Checked matrix multiplication, returning `None` on `u128` overflow
+fn checked_mul(a: Matrix2x2, b: Matrix2x2) -> Option<Matrix2x2> {
+    let ad = a.data;
+    let bd = b.data;
+
+    let mut out = [[0u128; 2]; 2];
+    for row in 0..2 {
+        for col in 0..2 {
+            out[row][col] = ad[row][0]
+                .checked_mul(bd[0][col])?
+                .checked_add(ad[row][1].checked_mul(bd[1][col])?)?;
+        }
+    }
+
+    Some(Matrix2x2::new(out))
+}
+
+/// Overflow-checked matrix exponentiation Fibonacci
+///
+/// Same repeated squaring as [`fib_matrix_fast`] but with checked arithmetic
+/// throughout, returning `Err(n)` instead of silently wrapping once the
+/// result exceeds `u128::MAX`. It raises the base matrix to `n - 1` and reads
+/// F(n) from the top-left entry, so no intermediate value is larger than F(n)
+/// itself and every n up to [`MAX_EXACT_N`] succeeds.
+///
+/// # Example
+/// ```
+/// use fib_core::matrix::fib_matrix_fast_checked;
+///
+/// assert_eq!(fib_matrix_fast_checked(100), Ok(354224848179261915075));
+/// assert_eq!(fib_matrix_fast_checked(187), Err(187));
+/// ```
+pub fn fib_matrix_fast_checked(n: u64) -> Result<u128, u64> {
+    if n == 0 {
+        return Ok(0);
+    }
+
+    let mut result = Matrix2x2::identity();
+    let mut base = Matrix2x2::fibonacci_base();
+    let mut exp = n - 1;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = checked_mul(result, base).ok_or(n)?;
+        }
+        exp /= 2;
+        if exp > 0 {
+            base = checked_mul(base, base).ok_or(n)?;
+        }
+    }
+
+    Ok(result.get(0, 0))
+}
+
+/// Overflow-safe matrix exponentiation Fibonacci, returning `None` instead of
+/// an overflowing index
+///
+/// A terser [`fib_matrix_fast_checked`] for callers who only care whether the
+/// value fit, not the overflowing n; see [`MAX_EXACT_N`] for the exact cutoff.
+///
+/// # Example
+/// ```
+/// use fib_core::matrix::checked_fib_matrix;
+///
+/// assert_eq!(checked_fib_matrix(100), Some(354224848179261915075));
+/// assert_eq!(checked_fib_matrix(187), None);
+/// ```
+pub fn checked_fib_matrix(n: u64) -> Option<u128> {
+    fib_matrix_fast_checked(n).ok()
+}
+
+/// Add `x + y` modulo `m`, where `x, y < m`, without overflowing `u128`
+///
+/// Plain `(x + y) % m` would overflow once `x + y` exceeds `u128::MAX`, which
+/// can happen for `m` close to `u128::MAX`. Since both operands are already
+/// reduced below `m`, `y >= m - x` tells us the sum would wrap past `m`, so we
+/// subtract first instead of adding.
+fn add_mod(x: u128, y: u128, m: u128) -> u128 {
+    if y >= m - x {
+        y - (m - x)
+    } else {
+        x + y
+    }
+}
+
+/// Multiply `a * b` modulo `m` without overflowing `u128`
+///
+/// Plain `(a * b) % m` overflows once `m > 2^64`, since both operands can be
+/// up to `m - 1`. This uses Russian-peasant (double-and-add) multiplication
+/// instead: walk the bits of `b`, doubling `a` (mod m) each step and
+/// accumulating it into the result whenever that bit of `b` is set. Every
+/// intermediate value stays below `m`, so no step can overflow.
+fn mulmod(a: u128, b: u128, m: u128) -> u128 {
+    let mut a = a % m;
+    let mut b = b;
+    let mut result = 0u128;
+
+    while b > 0 {
+        if b & 1 == 1 {
+            result = add_mod(result, a, m);
+        }
+        a = add_mod(a, a, m);
+        b >>= 1;
+    }
+
+    result
+}
+
 /// Matrix Fibonacci with modular arithmetic
 ///
 /// Computes F(n) mod m, useful for very large n where overflow would occur.
+/// Correct for any modulus up to `u128::MAX`: every product is folded
+/// through an overflow-free `mulmod` (Russian-peasant multiplication)
+/// instead of `(a * b) % m`, which would itself overflow once `m > 2^64`.
 ///
 /// # Complexity
-/// - Time: O(log n)
+/// - Time: O(log n · log m)
 /// - Space: O(1)
 ///
 /// # Example
@@ -134,12 +249,12 @@ pub fn fib_matrix_modulo(n: u64, modulo: u128) -> u128 {
     fn mul_mod(a: [[u128; 2]; 2], b: [[u128; 2]; 2], m: u128) -> [[u128; 2]; 2] {
         [
             [
-                ((a[0][0] * b[0][0]) % m + (a[0][1] * b[1][0]) % m) % m,
-                ((a[0][0] * b[0][1]) % m + (a[0][1] * b[1][1]) % m) % m,
+                add_mod(mulmod(a[0][0], b[0][0], m), mulmod(a[0][1], b[1][0], m), m),
+                add_mod(mulmod(a[0][0], b[0][1], m), mulmod(a[0][1], b[1][1], m), m),
             ],
             [
-                ((a[1][0] * b[0][0]) % m + (a[1][1] * b[1][0]) % m) % m,
-                ((a[1][0] * b[0][1]) % m + (a[1][1] * b[1][1]) % m) % m,
+                add_mod(mulmod(a[1][0], b[0][0], m), mulmod(a[1][1], b[1][0], m), m),
+                add_mod(mulmod(a[1][0], b[0][1], m), mulmod(a[1][1], b[1][1], m), m),
             ],
         ]
     }
@@ -194,6 +309,64 @@ pub fn fib_doubling(n: u64) -> u128 {
     fib_pair(n).0
 }
 
+/// Overflow-checked fast-doubling Fibonacci
+///
+/// Same doubling recurrence as [`fib_doubling`] but with checked arithmetic,
+/// returning `Err(n)` instead of panicking/wrapping once the result exceeds
+/// `u128::MAX`. It computes the pair `(F(n-1), F(n))`, so F(n+1) is never
+/// formed and every n up to [`MAX_EXACT_N`] succeeds.
+///
+/// # Example
+/// ```
+/// use fib_core::matrix::fib_doubling_checked;
+///
+/// assert_eq!(fib_doubling_checked(100), Ok(354224848179261915075));
+/// assert_eq!(fib_doubling_checked(187), Err(187));
+/// ```
+pub fn fib_doubling_checked(n: u64) -> Result<u128, u64> {
+    fn fib_pair_checked(n: u64) -> Option<(u128, u128)> {
+        if n == 0 {
+            return Some((0, 1));
+        }
+
+        let (f_k, f_k1) = fib_pair_checked(n / 2)?;
+
+        let two_f_k1 = f_k1.checked_mul(2)?;
+        let inner = two_f_k1.checked_sub(f_k)?;
+        let f_2k = f_k.checked_mul(inner)?;
+        let f_2k1 = f_k.checked_mul(f_k)?.checked_add(f_k1.checked_mul(f_k1)?)?;
+
+        if n & 1 == 0 {
+            Some((f_2k, f_2k1))
+        } else {
+            Some((f_2k1, f_2k.checked_add(f_2k1)?))
+        }
+    }
+
+    if n == 0 {
+        return Ok(0);
+    }
+
+    fib_pair_checked(n - 1).map(|(_, f)| f).ok_or(n)
+}
+
+/// Overflow-safe fast-doubling Fibonacci, returning `None` instead of an
+/// overflowing index
+///
+/// A terser [`fib_doubling_checked`] for callers who only care whether the
+/// value fit, not the overflowing n; see [`MAX_EXACT_N`] for the exact cutoff.
+///
+/// # Example
+/// ```
+/// use fib_core::matrix::checked_fib_doubling;
+///
+/// assert_eq!(checked_fib_doubling(100), Some(354224848179261915075));
+/// assert_eq!(checked_fib_doubling(187), None);
+/// ```
+pub fn checked_fib_doubling(n: u64) -> Option<u128> {
+    fib_doubling_checked(n).ok()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -228,6 +401,37 @@ mod tests {
         assert_eq!(fib_matrix_modulo(1000, modulo), 517691607);
     }
 
+    #[test]
+    fn test_fib_matrix_modulo_matches_biguint_for_modulus_above_2_64() {
+        use num_bigint::BigUint;
+        use num_traits::ToPrimitive;
+
+        // A modulus above u64::MAX so the naive `(a * b) % m` product would
+        // overflow u128 for operands close to m.
+        let modulo: u128 = (u64::MAX as u128) * 3 + 7;
+        let expected = (crate::bigint::fib_matrix_big(500) % BigUint::from(modulo))
+            .to_u128()
+            .unwrap();
+        assert_eq!(fib_matrix_modulo(500, modulo), expected);
+    }
+
+    #[test]
+    fn test_mulmod_matches_naive_for_small_operands() {
+        let m = 1_000_000_007u128;
+        for a in [0u128, 1, 2, 12345, m - 1] {
+            for b in [0u128, 1, 7, 54321, m - 1] {
+                assert_eq!(mulmod(a, b, m), (a * b) % m);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mulmod_no_overflow_near_u128_max() {
+        let m = u128::MAX;
+        assert_eq!(mulmod(m - 1, m - 1, m), 1);
+        assert_eq!(mulmod(m - 1, 0, m), 0);
+    }
+
     #[test]
     fn test_fib_doubling() {
         for n in 0..50 {
@@ -266,6 +470,40 @@ mod tests {
         assert_eq!(id * base, base);
     }
 
+    #[test]
+    fn test_fib_matrix_fast_checked_fits() {
+        assert_eq!(fib_matrix_fast_checked(100), Ok(fib_matrix_fast(100)));
+        assert_eq!(fib_matrix_fast_checked(186), Ok(fib_matrix_fast(186)));
+    }
+
+    #[test]
+    fn test_fib_matrix_fast_checked_overflow() {
+        assert_eq!(fib_matrix_fast_checked(187), Err(187));
+    }
+
+    #[test]
+    fn test_fib_doubling_checked_fits() {
+        assert_eq!(fib_doubling_checked(100), Ok(fib_doubling(100)));
+        assert_eq!(fib_doubling_checked(186), Ok(fib_matrix_fast(186)));
+    }
+
+    #[test]
+    fn test_fib_doubling_checked_overflow() {
+        assert_eq!(fib_doubling_checked(187), Err(187));
+    }
+
+    #[test]
+    fn test_checked_fib_matrix_fits_and_overflows() {
+        assert_eq!(checked_fib_matrix(MAX_EXACT_N), Some(fib_matrix_fast(MAX_EXACT_N)));
+        assert_eq!(checked_fib_matrix(MAX_EXACT_N + 1), None);
+    }
+
+    #[test]
+    fn test_checked_fib_doubling_fits_and_overflows() {
+        assert_eq!(checked_fib_doubling(MAX_EXACT_N), Some(fib_matrix_fast(MAX_EXACT_N)));
+        assert_eq!(checked_fib_doubling(MAX_EXACT_N + 1), None);
+    }
+
     #[test]
     fn test_fib_doubling_edge_cases() {
         assert_eq!(fib_doubling(0), 0);
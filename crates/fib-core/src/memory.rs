@@ -7,6 +7,7 @@ use crate::allocator::TrackingAllocator;
 pub struct MemoryStats {
     pub current_bytes: usize,
     pub allocations: usize,
+    pub peak_bytes: usize,
 }
 
 impl MemoryStats {
@@ -14,14 +15,22 @@ impl MemoryStats {
         Self {
             current_bytes: allocator.get_current_usage(),
             allocations: allocator.get_allocation_count(),
+            peak_bytes: allocator.get_peak_usage(),
         }
     }
 
     /// Calculate delta from a previous snapshot
+    ///
+    /// Note: `peak_bytes` is a high-water mark, not a running total, so the
+    /// delta reports this snapshot's peak as is rather than subtracting
+    /// (which wouldn't make sense for a max). That peak covers everything
+    /// since the allocator's last `reset`/`reset_peak`, which may be earlier
+    /// than when `start` was taken.
     pub fn delta(&self, start: &MemoryStats) -> Self {
         Self {
             current_bytes: self.current_bytes.saturating_sub(start.current_bytes),
             allocations: self.allocations.saturating_sub(start.allocations),
+            peak_bytes: self.peak_bytes,
         }
     }
 }
@@ -35,16 +44,19 @@ mod tests {
         let start = MemoryStats {
             current_bytes: 100,
             allocations: 5,
+            peak_bytes: 100,
         };
 
         let end = MemoryStats {
             current_bytes: 250,
             allocations: 8,
+            peak_bytes: 250,
         };
 
         let delta = end.delta(&start);
         assert_eq!(delta.current_bytes, 150);
         assert_eq!(delta.allocations, 3);
+        assert_eq!(delta.peak_bytes, 250);
     }
 
     #[test]
@@ -53,11 +65,13 @@ mod tests {
         let start = MemoryStats {
             current_bytes: 500,
             allocations: 10,
+            peak_bytes: 500,
         };
 
         let end = MemoryStats {
             current_bytes: 200, // less usage
             allocations: 12,    // more allocs count
+            peak_bytes: 500,
         };
 
         let delta = end.delta(&start);
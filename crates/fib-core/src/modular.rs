@@ -0,0 +1,281 @@
+//! Modular Fibonacci for arbitrary-size moduli
+//!
+//! [`matrix::fib_matrix_modulo`](crate::matrix::fib_matrix_modulo) only
+//! handles moduli that fit in a `u128`. This module computes F(n) mod m for a
+//! `BigUint` modulus `m` by running the same matrix-exponentiation identity
+//! with every multiply reduced mod `m`, so the intermediate values stay
+//! bounded by `m` no matter how large `n` is.
+
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+/// Matrix Fibonacci modulo an arbitrary-size `BigUint` modulus
+///
+/// # Complexity
+/// - Time: O(log n) big-integer multiplications, each bounded by `m`
+/// - Space: O(1) matrices, each bounded by `m`
+///
+/// # Example
+/// ```
+/// use fib_core::modular::fib_mod_direct;
+/// use num_bigint::BigUint;
+///
+/// let m = BigUint::from(1_000_000_007u32);
+/// assert_eq!(fib_mod_direct(10, &m), BigUint::from(55u32));
+/// ```
+pub fn fib_mod_direct(n: u64, m: &BigUint) -> BigUint {
+    if m.is_zero() {
+        panic!("modulus must be non-zero");
+    }
+    if n == 0 {
+        return Zero::zero();
+    }
+
+    let mut result: [[BigUint; 2]; 2] = [[One::one(), Zero::zero()], [Zero::zero(), One::one()]];
+    let mut base: [[BigUint; 2]; 2] = [[One::one(), One::one()], [One::one(), Zero::zero()]];
+    let mut exp = n;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_matrix_mod(&result, &base, m);
+        }
+        base = mul_matrix_mod(&base, &base, m);
+        exp /= 2;
+    }
+
+    result[0][1].clone()
+}
+
+/// Find the Pisano period: the period of the Fibonacci sequence mod `m`
+///
+/// Iterates the pair `(F(k) mod m, F(k+1) mod m)` starting from `(0, 1)`
+/// until it returns to `(0, 1)`, which is guaranteed to happen since there
+/// are only `m * m` possible pairs and the recurrence is reversible.
+///
+/// # Example
+/// ```
+/// use fib_core::modular::pisano_period;
+/// use num_bigint::BigUint;
+///
+/// // The Pisano period of 10 is 60.
+/// assert_eq!(pisano_period(&BigUint::from(10u32)), BigUint::from(60u32));
+/// ```
+pub fn pisano_period(m: &BigUint) -> BigUint {
+    if m.is_zero() {
+        panic!("modulus must be non-zero");
+    }
+    if m.is_one() {
+        return One::one();
+    }
+
+    let (mut a, mut b): (BigUint, BigUint) = (Zero::zero(), One::one());
+    let mut period: BigUint = Zero::zero();
+
+    loop {
+        let next = (&a + &b) % m;
+        a = b;
+        b = next;
+        period += 1u32;
+
+        if a.is_zero() && b.is_one() {
+            return period;
+        }
+    }
+}
+
+/// Compute F(n) mod m, reducing `n` modulo the Pisano period first
+///
+/// This makes evaluating astronomically large indices constant-time in `n`
+/// (beyond computing the period itself, which only depends on `m`).
+///
+/// # Example
+/// ```
+/// use fib_core::modular::fib_mod;
+/// use num_bigint::BigUint;
+///
+/// let m = BigUint::from(10u32);
+/// assert_eq!(fib_mod(1_000_000u64, &m), fib_mod(1_000_000u64 % 60, &m));
+/// ```
+pub fn fib_mod(n: u64, m: &BigUint) -> BigUint {
+    let period = pisano_period(m);
+    let reduced_n = BigUint::from(n) % &period;
+    // `reduced_n` is strictly smaller than the period, which comfortably fits
+    // in a u64 for any modulus this function can practically be run with.
+    fib_mod_direct(reduced_n.iter_u64_digits().next().unwrap_or(0), m)
+}
+
+/// Find the Pisano period for a native `u128` modulus
+///
+/// Same idea as [`pisano_period`], but operates on plain `u128` arithmetic
+/// instead of `BigUint` — much cheaper for the moduli (`m` fitting in a
+/// machine word) this crate's other `u128`-based code actually uses.
+///
+/// # Example
+/// ```
+/// use fib_core::modular::pisano_period_u128;
+///
+/// assert_eq!(pisano_period_u128(10), 60);
+/// ```
+pub fn pisano_period_u128(m: u128) -> u64 {
+    assert!(m != 0, "modulus must be non-zero");
+    if m == 1 {
+        return 1;
+    }
+
+    let (mut a, mut b) = (0u128, 1u128);
+    let mut period = 0u64;
+
+    loop {
+        let next = (a + b) % m;
+        a = b;
+        b = next;
+        period += 1;
+
+        if a == 0 && b == 1 {
+            return period;
+        }
+    }
+}
+
+/// Find the Pisano period of `m` given its prime factorization, using the
+/// multiplicative structure of π instead of direct iteration
+///
+/// For a prime power `p^k`, `π(p^k) = p^(k-1) · π(p)`; for `m` with
+/// coprime prime-power factors `p_1^k_1, ..., p_r^k_r`,
+/// `π(m) = lcm(π(p_1^k_1), ..., π(p_r^k_r))`. This replaces the O(m) direct
+/// iteration in [`pisano_period_u128`] with one direct iteration per prime
+/// `p`, so it saves work when `m`'s prime factors are small; for a prime `m`
+/// it iterates over `m` itself just like the direct route. The caller must
+/// supply `m`'s factorization as `(prime, exponent)` pairs.
+///
+/// # Example
+/// ```
+/// use fib_core::modular::pisano_period_factored;
+///
+/// // 10 = 2^1 * 5^1
+/// assert_eq!(pisano_period_factored(&[(2, 1), (5, 1)]), 60);
+/// ```
+pub fn pisano_period_factored(factors: &[(u128, u32)]) -> u64 {
+    assert!(!factors.is_empty(), "factorization must not be empty");
+
+    factors
+        .iter()
+        .map(|&(p, k)| {
+            let pi_p = pisano_period_u128(p);
+            // π(p^k) = p^(k-1) * π(p)
+            (p.pow(k - 1) as u64) * pi_p
+        })
+        .fold(1u64, lcm)
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn lcm(a: u64, b: u64) -> u64 {
+    a / gcd(a, b) * b
+}
+
+fn mul_matrix_mod(
+    a: &[[BigUint; 2]; 2],
+    b: &[[BigUint; 2]; 2],
+    m: &BigUint,
+) -> [[BigUint; 2]; 2] {
+    [
+        [
+            (&a[0][0] * &b[0][0] + &a[0][1] * &b[1][0]) % m,
+            (&a[0][0] * &b[0][1] + &a[0][1] * &b[1][1]) % m,
+        ],
+        [
+            (&a[1][0] * &b[0][0] + &a[1][1] * &b[1][0]) % m,
+            (&a[1][0] * &b[0][1] + &a[1][1] * &b[1][1]) % m,
+        ],
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bigint::fib_matrix_big;
+
+    #[test]
+    fn test_fib_mod_direct_matches_bigint_mod() {
+        let m = BigUint::from(1_000_000_007u32);
+        for n in [0u64, 1, 10, 50, 100, 1000] {
+            assert_eq!(fib_mod_direct(n, &m), fib_matrix_big(n) % &m);
+        }
+    }
+
+    #[test]
+    fn test_pisano_period_known_values() {
+        assert_eq!(pisano_period(&BigUint::from(1u32)), BigUint::from(1u32));
+        assert_eq!(pisano_period(&BigUint::from(2u32)), BigUint::from(3u32));
+        assert_eq!(pisano_period(&BigUint::from(10u32)), BigUint::from(60u32));
+        assert_eq!(pisano_period(&BigUint::from(16u32)), BigUint::from(24u32));
+    }
+
+    #[test]
+    fn test_fib_mod_matches_direct_for_small_n() {
+        let m = BigUint::from(1_000_000_007u32);
+        for n in [0u64, 1, 10, 50, 100, 1000] {
+            assert_eq!(fib_mod(n, &m), fib_mod_direct(n, &m));
+        }
+    }
+
+    #[test]
+    fn test_fib_mod_reduces_large_n_via_period() {
+        let m = BigUint::from(10u32);
+        let period = pisano_period(&m); // 60
+
+        let huge_n = 60u64 * 1_000_000 + 7;
+        assert_eq!(fib_mod(huge_n, &m), fib_mod_direct(7, &m));
+        assert_eq!(period, BigUint::from(60u32));
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus must be non-zero")]
+    fn test_pisano_period_zero_modulus_panics() {
+        pisano_period(&BigUint::zero());
+    }
+
+    #[test]
+    fn test_pisano_period_u128_known_values() {
+        assert_eq!(pisano_period_u128(1), 1);
+        assert_eq!(pisano_period_u128(2), 3);
+        assert_eq!(pisano_period_u128(10), 60);
+        assert_eq!(pisano_period_u128(16), 24);
+    }
+
+    #[test]
+    fn test_pisano_period_u128_matches_biguint_version() {
+        for m in [1u128, 2, 3, 7, 10, 16, 47, 100] {
+            assert_eq!(
+                pisano_period_u128(m),
+                pisano_period(&BigUint::from(m)).iter_u64_digits().next().unwrap_or(0)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus must be non-zero")]
+    fn test_pisano_period_u128_zero_modulus_panics() {
+        pisano_period_u128(0);
+    }
+
+    #[test]
+    fn test_pisano_period_factored_matches_direct() {
+        // 10 = 2 * 5
+        assert_eq!(pisano_period_factored(&[(2, 1), (5, 1)]), 60);
+        // 16 = 2^4
+        assert_eq!(pisano_period_factored(&[(2, 4)]), 24);
+        // 100 = 2^2 * 5^2
+        assert_eq!(
+            pisano_period_factored(&[(2, 2), (5, 2)]),
+            pisano_period_u128(100)
+        );
+    }
+}
@@ -0,0 +1,140 @@
+//! Number-theory helpers that pair naturally with Fibonacci: GCD, the
+//! `gcd(F(m), F(n)) = F(gcd(m, n))` identity, and factorials.
+
+/// Binary (Stein's) GCD over `u128`
+///
+/// Avoids the division used by the Euclidean algorithm, replacing it with
+/// shifts and subtraction: strip the shared power of two from `a` and `b`
+/// (tracking it as `k`), then repeatedly halve whichever operand is still
+/// even and subtract the smaller from the larger until they're equal, and
+/// shift the result back up by `k`.
+///
+/// # Example
+/// ```
+/// use fib_core::number_theory::gcd;
+///
+/// assert_eq!(gcd(48, 18), 6);
+/// assert_eq!(gcd(0, 5), 5);
+/// assert_eq!(gcd(5, 0), 5);
+/// ```
+pub fn gcd(mut a: u128, mut b: u128) -> u128 {
+    if a == 0 {
+        return b;
+    }
+    if b == 0 {
+        return a;
+    }
+
+    let k = (a | b).trailing_zeros();
+    a >>= a.trailing_zeros();
+
+    loop {
+        b >>= b.trailing_zeros();
+        if a > b {
+            core::mem::swap(&mut a, &mut b);
+        }
+        b -= a;
+        if b == 0 {
+            break;
+        }
+    }
+
+    a << k
+}
+
+/// `F(gcd(m, n))` via the identity `gcd(F(m), F(n)) = F(gcd(m, n))`
+///
+/// Far cheaper than computing `F(m)` and `F(n)` in full and taking their
+/// GCD, since `gcd(m, n) <= min(m, n)` — often much smaller — and this only
+/// ever evaluates a single Fibonacci number.
+///
+/// # Example
+/// ```
+/// use fib_core::number_theory::fib_gcd;
+///
+/// // gcd(F(12), F(18)) = F(gcd(12, 18)) = F(6) = 8
+/// assert_eq!(fib_gcd(12, 18), 8);
+/// ```
+pub fn fib_gcd(m: u64, n: u64) -> u128 {
+    crate::matrix::fib_doubling(gcd(m as u128, n as u128) as u64)
+}
+
+/// Iterative factorial with `u128` overflow detection
+///
+/// # Example
+/// ```
+/// use fib_core::number_theory::factorial;
+///
+/// assert_eq!(factorial(5), Some(120));
+/// assert_eq!(factorial(0), Some(1));
+/// assert!(factorial(100).is_none()); // overflows u128
+/// ```
+pub fn factorial(n: u64) -> Option<u128> {
+    let mut result = 1u128;
+    for i in 2..=n {
+        result = result.checked_mul(i as u128)?;
+    }
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_known_values() {
+        assert_eq!(gcd(48, 18), 6);
+        assert_eq!(gcd(18, 48), 6);
+        assert_eq!(gcd(7, 13), 1);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(gcd(5, 0), 5);
+        assert_eq!(gcd(0, 0), 0);
+    }
+
+    #[test]
+    fn test_gcd_matches_euclidean() {
+        fn euclidean(a: u128, b: u128) -> u128 {
+            if b == 0 {
+                a
+            } else {
+                euclidean(b, a % b)
+            }
+        }
+
+        for a in 0u128..40 {
+            for b in 0u128..40 {
+                assert_eq!(gcd(a, b), euclidean(a, b), "mismatch at ({a}, {b})");
+            }
+        }
+    }
+
+    #[test]
+    fn test_fib_gcd_identity_matches_direct_gcd() {
+        use crate::matrix::fib_doubling;
+
+        for (m, n) in [(12, 18), (10, 15), (7, 7), (6, 9), (20, 30)] {
+            let direct = gcd(fib_doubling(m), fib_doubling(n));
+            assert_eq!(fib_gcd(m, n), direct, "mismatch at ({m}, {n})");
+        }
+    }
+
+    #[test]
+    fn test_fib_gcd_zero_index() {
+        // gcd(0, n) == n, so fib_gcd(0, n) == F(n), not F(0).
+        assert_eq!(fib_gcd(0, 5), 5);
+        assert_eq!(fib_gcd(5, 0), 5);
+    }
+
+    #[test]
+    fn test_factorial_known_values() {
+        assert_eq!(factorial(0), Some(1));
+        assert_eq!(factorial(1), Some(1));
+        assert_eq!(factorial(5), Some(120));
+        assert_eq!(factorial(10), Some(3_628_800));
+    }
+
+    #[test]
+    fn test_factorial_overflow() {
+        assert!(factorial(35).is_none());
+    }
+}
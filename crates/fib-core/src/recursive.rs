@@ -8,6 +8,11 @@
 //! The naive recursive implementation has O(2^n) complexity and should only
 //! be used for demonstration purposes with small n values (n < 30).
 
+#[cfg(feature = "std")]
+use std::vec;
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::vec;
+
 /// Naive recursive Fibonacci - for demonstration only
 ///
 /// # Complexity
@@ -49,6 +54,7 @@ pub fn fib_recursive(n: u64) -> u128 {
 /// assert_eq!(fib_recursive_memo(50), 12586269025);
 /// assert_eq!(fib_recursive_memo(100), 354224848179261915075);
 /// ```
+#[cfg(any(feature = "std", feature = "alloc"))]
 pub fn fib_recursive_memo(n: u64) -> u128 {
     if n == 0 {
         return 0;
@@ -57,6 +63,7 @@ pub fn fib_recursive_memo(n: u64) -> u128 {
     fib_recursive_memo_impl(n, &mut memo)
 }
 
+#[cfg(any(feature = "std", feature = "alloc"))]
 #[inline]
 fn fib_recursive_memo_impl(n: u64, memo: &mut [u128]) -> u128 {
     if n <= 1 {
@@ -71,6 +78,39 @@ fn fib_recursive_memo_impl(n: u64, memo: &mut [u128]) -> u128 {
     memo[n as usize]
 }
 
+/// Tail-recursive accumulator Fibonacci
+///
+/// Carries the running pair `(penultimate, last)` down the call chain instead
+/// of combining results on the way back up, so each call is a tail call and
+/// the function runs in O(1) heap allocation (no memo vector, unlike
+/// [`fib_recursive_memo`]).
+///
+/// # Complexity
+/// - Time: O(n)
+/// - Space: O(1) heap / O(n) call stack (tail-call optimization is not
+///   guaranteed by the Rust compiler, so very large n can still overflow the
+///   stack, but no heap allocation occurs)
+///
+/// # Example
+/// ```
+/// use fib_core::recursive::fib_recursive_acc;
+///
+/// assert_eq!(fib_recursive_acc(0), 0);
+/// assert_eq!(fib_recursive_acc(10), 55);
+/// assert_eq!(fib_recursive_acc(50), 12586269025);
+/// ```
+pub fn fib_recursive_acc(n: u64) -> u128 {
+    fn inner(n: u64, penultimate: u128, last: u128) -> u128 {
+        if n == 0 {
+            penultimate
+        } else {
+            inner(n - 1, last, penultimate + last)
+        }
+    }
+
+    inner(n, 0, 1)
+}
+
 /// Count the number of recursive calls for naive implementation
 ///
 /// Useful for demonstrating the exponential nature of the naive approach.
@@ -112,12 +152,32 @@ mod tests {
     }
 
     #[test]
+    #[cfg(any(feature = "std", feature = "alloc"))]
     fn test_fib_recursive_memo() {
         assert_eq!(fib_recursive_memo(0), 0);
         assert_eq!(fib_recursive_memo(1), 1);
         assert_eq!(fib_recursive_memo(50), 12586269025);
     }
 
+    #[test]
+    fn test_fib_recursive_acc_base_cases() {
+        assert_eq!(fib_recursive_acc(0), 0);
+        assert_eq!(fib_recursive_acc(1), 1);
+        assert_eq!(fib_recursive_acc(2), 1);
+    }
+
+    #[test]
+    fn test_fib_recursive_acc_matches_iterative() {
+        for n in 0..100 {
+            assert_eq!(
+                fib_recursive_acc(n),
+                crate::iterative::fib_iterative(n),
+                "Mismatch at n={}",
+                n
+            );
+        }
+    }
+
     #[test]
     fn test_count_calls_grows_exponentially() {
         let calls_10 = count_recursive_calls(10);
@@ -139,6 +139,12 @@ impl std::fmt::Display for SimdFeatures {
 /// # }
 /// ```
 pub fn fib_simd_batch(indices: &[u64]) -> Vec<u64> {
+    // Below this, the doubling loop's setup (computing bit length, looping
+    // over bits) costs more than just linearly iterating the handful of
+    // steps `fib_simd_4` would take, so keep the original linear path as the
+    // fallback for tiny indices.
+    const LINEAR_FALLBACK_MAX_N: u64 = 8;
+
     let mut results = Vec::with_capacity(indices.len());
     let chunks = indices.chunks_exact(4);
     let remainder = chunks.remainder();
@@ -146,7 +152,13 @@ pub fn fib_simd_batch(indices: &[u64]) -> Vec<u64> {
     // Process 4 indices at a time using SIMD
     for chunk in chunks {
         let batch: [u64; 4] = [chunk[0], chunk[1], chunk[2], chunk[3]];
-        let simd_results = fib_simd_4(batch);
+        let max_n = batch.iter().copied().max().unwrap_or(0);
+
+        let simd_results = if max_n <= LINEAR_FALLBACK_MAX_N {
+            fib_simd_4(batch)
+        } else {
+            fib_simd_4_doubling(batch)
+        };
         results.extend_from_slice(&simd_results);
     }
 
@@ -201,6 +213,53 @@ fn fib_simd_4(indices: [u64; 4]) -> [u64; 4] {
     a.to_array()
 }
 
+/// Calculate 4 Fibonacci numbers simultaneously using SIMD fast-doubling
+///
+/// Unlike [`fib_simd_4`], which iterates linearly up to the batch's largest
+/// index, this walks the bits of the indices from most-significant to
+/// least-significant, maintaining per-lane pairs `(F(k), F(k+1))` and
+/// applying the doubling identities `F(2k) = F(k)(2F(k+1) - F(k))`,
+/// `F(2k+1) = F(k)^2 + F(k+1)^2` at every bit, each lane additionally
+/// advancing `k -> 2k+1` (via `blend`) whenever its own bit is set. This
+/// costs O(max bit length) vector iterations instead of O(max index), so a
+/// batch containing both F(5) and F(90) costs ~7 steps instead of 90.
+///
+/// Lanes whose index has fewer significant bits than the batch maximum are
+/// unaffected by the extra leading iterations: doubling `(0, 1)` (the k=0
+/// starting pair) with an unset bit yields `(0, 1)` again, so leading
+/// zero-bits are a no-op for those lanes.
+#[inline]
+fn fib_simd_4_doubling(indices: [u64; 4]) -> [u64; 4] {
+    let max_bits = indices
+        .iter()
+        .copied()
+        .map(|n| 64 - n.leading_zeros())
+        .max()
+        .unwrap_or(0);
+
+    let mut f_k = u64x4::from([0, 0, 0, 0]);
+    let mut f_k1 = u64x4::from([1, 1, 1, 1]);
+
+    for bit in (0..max_bits).rev() {
+        let two_f_k1 = f_k1 + f_k1;
+        let f_2k = f_k * (two_f_k1 - f_k);
+        let f_2k1 = f_k * f_k + f_k1 * f_k1;
+
+        let bit_vals: [u64; 4] = indices.map(|n| (n >> bit) & 1);
+        let bit_set = u64x4::from(bit_vals).cmp_gt(u64x4::splat(0));
+
+        // bit == 0: (f_k, f_k1) <- (f_2k, f_2k1)          [k -> 2k]
+        // bit == 1: (f_k, f_k1) <- (f_2k1, f_2k + f_2k1)  [k -> 2k+1]
+        let next_f_k = bit_set.blend(f_2k1, f_2k);
+        let next_f_k1 = bit_set.blend(f_2k + f_2k1, f_2k1);
+
+        f_k = next_f_k;
+        f_k1 = next_f_k1;
+    }
+
+    f_k.to_array()
+}
+
 /// Scalar Fibonacci calculation for fallback
 #[inline]
 fn fib_scalar(n: u64) -> u64 {
@@ -251,7 +310,11 @@ impl SimdBatchCalculator {
 
     /// Compare SIMD vs scalar performance
     ///
-    /// Returns (simd_duration, scalar_duration) in nanoseconds
+    /// Returns (simd_duration, scalar_duration) in nanoseconds. `simd_duration`
+    /// reflects whichever path [`fib_simd_batch`] picks per-chunk (linear for
+    /// small indices, doubling otherwise); see
+    /// [`benchmark_linear_vs_doubling`](Self::benchmark_linear_vs_doubling) to
+    /// measure the two SIMD strategies directly against each other.
     pub fn benchmark(&self, indices: &[u64], iterations: u32) -> (u128, u128) {
         use std::time::Instant;
 
@@ -271,6 +334,47 @@ impl SimdBatchCalculator {
 
         (simd_ns / iterations as u128, scalar_ns / iterations as u128)
     }
+
+    /// Compare the linear-iteration and fast-doubling SIMD strategies
+    /// directly, independent of [`fib_simd_batch`]'s own size-based choice
+    /// between them
+    ///
+    /// Returns `(linear_ns, doubling_ns)`, each averaged over `iterations`
+    /// and padded to a multiple of 4 (both kernels operate on 4-wide
+    /// chunks); the gap grows with the spread between the batch's smallest
+    /// and largest index, since linear cost scales with the largest index
+    /// while doubling cost scales with its bit length.
+    pub fn benchmark_linear_vs_doubling(&self, indices: &[u64], iterations: u32) -> (u128, u128) {
+        use std::time::Instant;
+
+        let mut padded = indices.to_vec();
+        while padded.len() % 4 != 0 {
+            padded.push(0);
+        }
+
+        let start = Instant::now();
+        for _ in 0..iterations {
+            for chunk in padded.chunks_exact(4) {
+                let batch: [u64; 4] = [chunk[0], chunk[1], chunk[2], chunk[3]];
+                let _ = std::hint::black_box(fib_simd_4(std::hint::black_box(batch)));
+            }
+        }
+        let linear_ns = start.elapsed().as_nanos();
+
+        let start = Instant::now();
+        for _ in 0..iterations {
+            for chunk in padded.chunks_exact(4) {
+                let batch: [u64; 4] = [chunk[0], chunk[1], chunk[2], chunk[3]];
+                let _ = std::hint::black_box(fib_simd_4_doubling(std::hint::black_box(batch)));
+            }
+        }
+        let doubling_ns = start.elapsed().as_nanos();
+
+        (
+            linear_ns / iterations as u128,
+            doubling_ns / iterations as u128,
+        )
+    }
 }
 
 impl Default for SimdBatchCalculator {
@@ -368,4 +472,33 @@ mod tests {
         assert_eq!(results[10], 55);
         assert_eq!(results[20], 6765);
     }
+
+    #[test]
+    fn test_fib_simd_4_doubling_matches_scalar() {
+        for batch in [[0u64, 1, 2, 3], [5, 10, 15, 20], [40, 41, 42, 43]] {
+            let doubling = fib_simd_4_doubling(batch);
+            let scalar = batch.map(fib_scalar);
+            assert_eq!(doubling, scalar, "Mismatch for batch {:?}", batch);
+        }
+    }
+
+    #[test]
+    fn test_fib_simd_4_doubling_mixed_magnitude_lanes() {
+        // A batch spanning a small and a large index exercises the
+        // "leading zero bits are a no-op" property for the smaller lanes.
+        let batch = [5, 90, 1, 60];
+        let doubling = fib_simd_4_doubling(batch);
+        let scalar = batch.map(fib_scalar);
+        assert_eq!(doubling, scalar);
+    }
+
+    #[test]
+    fn test_fib_simd_batch_uses_doubling_for_large_indices() {
+        // Past the linear-fallback threshold, fib_simd_batch should route
+        // through fib_simd_4_doubling and still match scalar results.
+        let indices = [50u64, 60, 70, 80];
+        let batch_results = fib_simd_batch(&indices);
+        let doubling_results = fib_simd_4_doubling(indices);
+        assert_eq!(batch_results, doubling_results.to_vec());
+    }
 }
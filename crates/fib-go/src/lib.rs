@@ -16,6 +16,16 @@
 
 use std::time::{Duration, Instant};
 
+mod stats;
+mod subprocess;
+pub use subprocess::{spawn_default, SubprocessRunner, DEFAULT_GO_SCRIPT, DEFAULT_PYTHON_SCRIPT};
+
+/// Number of per-call samples collected from a subprocess backend per
+/// method, by repeating a small batch and treating each batch's average as
+/// one sample (the subprocess protocol only reports one aggregate elapsed
+/// time per round-trip, not per-call timings).
+const SUBPROCESS_BATCHES: u32 = 20;
+
 // When CGO is available, use FFI
 #[cfg(not(use_rust_stub))]
 mod ffi {
@@ -265,6 +275,42 @@ impl GoFibMethod {
             GoFibMethod::Doubling,
         ]
     }
+
+    /// The id this method is known by in the subprocess line protocol (see
+    /// [`subprocess`]), matching the method names in `go/subprocess/main.go`
+    /// and `scripts/fib_subprocess.py`.
+    pub fn protocol_id(&self) -> &'static str {
+        match self {
+            GoFibMethod::Iterative => "iterative",
+            GoFibMethod::Recursive => "recursive",
+            GoFibMethod::Memoized => "memoized",
+            GoFibMethod::Matrix => "matrix",
+            GoFibMethod::Doubling => "doubling",
+        }
+    }
+}
+
+/// Which transport [`compare_implementations`] uses to reach the Go side
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GoBackend {
+    /// The CGO `extern "C"` bridge in the `ffi` module (falls back to a
+    /// pure-Rust stub when no C toolchain is available)
+    Ffi,
+    /// A `go run` (or `python3` fallback) child process speaking the
+    /// [`subprocess`] line protocol, so comparisons work without CGO at all
+    Subprocess,
+}
+
+impl core::str::FromStr for GoBackend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "ffi" => Ok(GoBackend::Ffi),
+            "subprocess" => Ok(GoBackend::Subprocess),
+            _ => Err(format!("Unknown Go backend: {}", s)),
+        }
+    }
 }
 
 /// Calculate Fibonacci using Go's iterative implementation
@@ -329,12 +375,53 @@ pub struct BenchmarkResult {
     pub result: u64,
     /// Average execution time
     pub avg_time: Duration,
+    /// Median execution time, robust to a single slow outlier sample
+    pub median: Duration,
+    /// Standard deviation across the kept (non-outlier) samples
+    pub std_dev: Duration,
+    /// Lower bound of the 95% bootstrap confidence interval around the mean
+    pub ci_low: Duration,
+    /// Upper bound of the 95% bootstrap confidence interval around the mean
+    pub ci_high: Duration,
     /// Number of iterations
     pub iterations: u32,
+    /// How this result's timing was collected, e.g. `"Native"`, `"FFI"`, or
+    /// `"Subprocess (go run)"` / `"Subprocess (python3)"`
+    pub transport: String,
 }
 
-/// Compare Rust and Go implementations for a given n
-pub fn compare_implementations(n: u64, iterations: u32) -> Vec<BenchmarkResult> {
+/// Build a [`BenchmarkResult`] from raw per-iteration nanosecond samples,
+/// discarding median-absolute-deviation outliers and computing a bootstrap
+/// confidence interval via [`stats::summarize`].
+#[allow(clippy::too_many_arguments)]
+fn benchmark_result_from_samples(
+    method: &str,
+    language: &str,
+    n: u64,
+    result: u64,
+    samples_ns: &[f64],
+    iterations: u32,
+    transport: &str,
+) -> BenchmarkResult {
+    let summary = stats::summarize(samples_ns);
+    BenchmarkResult {
+        method: method.to_string(),
+        language: language.to_string(),
+        n,
+        result,
+        avg_time: Duration::from_nanos(summary.mean_ns.max(0.0) as u64),
+        median: Duration::from_nanos(summary.median_ns.max(0.0) as u64),
+        std_dev: Duration::from_nanos(summary.std_dev_ns.max(0.0) as u64),
+        ci_low: Duration::from_nanos(summary.ci_low_ns.max(0.0) as u64),
+        ci_high: Duration::from_nanos(summary.ci_high_ns.max(0.0) as u64),
+        iterations,
+        transport: transport.to_string(),
+    }
+}
+
+/// Compare Rust and Go implementations for a given n, reaching the Go side
+/// through `backend` (see [`GoBackend`])
+pub fn compare_implementations(n: u64, iterations: u32, backend: GoBackend) -> Vec<BenchmarkResult> {
     use fib_core::{recursive, FibMethod};
 
     let mut results = Vec::new();
@@ -346,26 +433,62 @@ pub fn compare_implementations(n: u64, iterations: u32) -> Vec<BenchmarkResult>
     ];
 
     for (name, method) in rust_methods {
-        let mut total = Duration::ZERO;
+        let mut samples_ns = Vec::with_capacity(iterations as usize);
         let mut result = 0u128;
 
         for _ in 0..iterations {
             let start = Instant::now();
             result = method.calculate(n);
-            total += start.elapsed();
+            samples_ns.push(start.elapsed().as_nanos() as f64);
         }
 
-        results.push(BenchmarkResult {
-            method: name.to_string(),
-            language: "Rust".to_string(),
+        results.push(benchmark_result_from_samples(
+            name,
+            "Rust",
             n,
-            result: result as u64,
-            avg_time: total / iterations,
+            result as u64,
+            &samples_ns,
             iterations,
-        });
+            "Native",
+        ));
+    }
+
+    // Rust memoized, for smaller n only
+    if n <= 10000 {
+        let mut samples_ns = Vec::with_capacity(iterations as usize);
+        let mut result = 0u128;
+        for _ in 0..iterations {
+            let start = Instant::now();
+            result = recursive::fib_recursive_memo(n);
+            samples_ns.push(start.elapsed().as_nanos() as f64);
+        }
+        results.push(benchmark_result_from_samples(
+            "Memoized",
+            "Rust",
+            n,
+            result as u64,
+            &samples_ns,
+            iterations,
+            "Native",
+        ));
+    }
+
+    match backend {
+        GoBackend::Ffi => compare_go_ffi(n, iterations, &mut results),
+        GoBackend::Subprocess => compare_go_subprocess(n, iterations, &mut results),
     }
 
-    // Go implementations
+    results
+}
+
+/// Time the Go side through the CGO `ffi` bridge (or its Rust-stub fallback)
+fn compare_go_ffi(n: u64, iterations: u32, results: &mut Vec<BenchmarkResult>) {
+    let transport = if is_go_available() {
+        "FFI".to_string()
+    } else {
+        "FFI (Rust stub)".to_string()
+    };
+
     let go_methods = [
         ("Iterative", GoFibMethod::Iterative),
         ("Matrix", GoFibMethod::Matrix),
@@ -373,78 +496,123 @@ pub fn compare_implementations(n: u64, iterations: u32) -> Vec<BenchmarkResult>
     ];
 
     for (name, method) in go_methods {
-        let mut total = Duration::ZERO;
+        let mut samples_ns = Vec::with_capacity(iterations as usize);
         let mut result = 0u64;
 
         for _ in 0..iterations {
             let start = Instant::now();
             result = method.calculate(n);
-            total += start.elapsed();
+            samples_ns.push(start.elapsed().as_nanos() as f64);
         }
 
-        results.push(BenchmarkResult {
-            method: name.to_string(),
-            language: "Go".to_string(),
+        results.push(benchmark_result_from_samples(
+            name,
+            "Go",
             n,
             result,
-            avg_time: total / iterations,
+            &samples_ns,
             iterations,
-        });
+            &transport,
+        ));
     }
 
-    // Also compare memoized for smaller n
     if n <= 10000 {
-        // Rust memoized
-        let mut total = Duration::ZERO;
-        let mut result = 0u128;
+        let mut samples_ns = Vec::with_capacity(iterations as usize);
+        let mut result = 0u64;
         for _ in 0..iterations {
             let start = Instant::now();
-            result = recursive::fib_recursive_memo(n);
-            total += start.elapsed();
+            result = go_fib_memo(n);
+            samples_ns.push(start.elapsed().as_nanos() as f64);
         }
-        results.push(BenchmarkResult {
-            method: "Memoized".to_string(),
-            language: "Rust".to_string(),
+        results.push(benchmark_result_from_samples(
+            "Memoized",
+            "Go",
             n,
-            result: result as u64,
-            avg_time: total / iterations,
+            result,
+            &samples_ns,
             iterations,
-        });
+            &transport,
+        ));
+    }
+}
+
+/// Time the Go side through a `go run`/`python3` child process, falling
+/// back gracefully (just logging a warning and contributing no Go rows) if
+/// neither is available
+fn compare_go_subprocess(n: u64, iterations: u32, results: &mut Vec<BenchmarkResult>) {
+    let mut runner = match spawn_default() {
+        Some(runner) => runner,
+        None => {
+            eprintln!(
+                "⚠️  Could not start a Go or Python subprocess (tried `go run {}` and `python3 {}`); skipping Go results",
+                DEFAULT_GO_SCRIPT, DEFAULT_PYTHON_SCRIPT
+            );
+            return;
+        }
+    };
 
-        // Go memoized
-        let mut total = Duration::ZERO;
+    // The subprocess protocol only reports one aggregate elapsed time per
+    // `run_batch` round-trip, not per-call timings, so samples are gathered
+    // by repeating several small batches and treating each batch's average
+    // per-call time as one sample.
+    let batches = SUBPROCESS_BATCHES.min(iterations).max(1);
+    let batch_size = (iterations / batches).max(1);
+
+    for method in GoFibMethod::all() {
+        let mut samples_ns = Vec::with_capacity(batches as usize);
         let mut result = 0u64;
-        for _ in 0..iterations {
-            let start = Instant::now();
-            result = go_fib_memo(n);
-            total += start.elapsed();
+        let mut failed = false;
+
+        for _ in 0..batches {
+            match runner.run_batch(method.protocol_id(), n, batch_size) {
+                Ok((elapsed_ns, batch_result)) => {
+                    result = batch_result;
+                    samples_ns.push(elapsed_ns / batch_size as f64);
+                }
+                Err(e) => {
+                    eprintln!(
+                        "⚠️  Subprocess benchmark for {} failed: {}",
+                        method.name(),
+                        e
+                    );
+                    failed = true;
+                    break;
+                }
+            }
         }
-        results.push(BenchmarkResult {
-            method: "Memoized".to_string(),
-            language: "Go".to_string(),
+
+        if failed || samples_ns.is_empty() {
+            continue;
+        }
+
+        results.push(benchmark_result_from_samples(
+            method.name().trim_start_matches("Go "),
+            "Go",
             n,
             result,
-            avg_time: total / iterations,
-            iterations,
-        });
+            &samples_ns,
+            batches * batch_size,
+            runner.transport_label(),
+        ));
     }
-
-    results
 }
 
 /// Format benchmark results as a table
 pub fn format_comparison_table(results: &[BenchmarkResult]) -> String {
     let mut output = String::new();
 
-    output.push_str(&format!("\n{:â”€^80}\n", " Rust vs Go Fibonacci Comparison "));
+    output.push_str(&format!("\n{:â”€^150}\n", " Rust vs Go Fibonacci Comparison "));
     output.push_str(&format!(
-        "| {:^12} | {:^8} | {:^15} | {:^15} | {:^12} |\n",
-        "Method", "Language", "n", "Time (avg)", "Result"
+        "| {:^12} | {:^8} | {:^15} | {:^15} | {:^15} | {:^15} | {:^25} | {:^12} | {:^18} |\n",
+        "Method", "Language", "n", "Time (avg)", "Median", "Std Dev", "95% CI", "Result", "Transport"
     ));
-    output.push_str(&format!("{:â”€^80}\n", ""));
+    output.push_str(&format!("{:â”€^150}\n", ""));
 
     for r in results {
         let time_str = format!("{:?}", r.avg_time);
+        let median_str = format!("{:?}", r.median);
+        let std_dev_str = format!("{:?}", r.std_dev);
+        let ci_str = format!("[{:?}, {:?}]", r.ci_low, r.ci_high);
         let result_str = if r.result > 999_999_999 {
             format!("{}...", &r.result.to_string()[..10])
         } else {
@@ -452,12 +620,12 @@ pub fn format_comparison_table(results: &[BenchmarkResult]) -> String {
         };
 
         output.push_str(&format!(
-            "| {:^12} | {:^8} | {:^15} | {:^15} | {:^12} |\n",
-            r.method, r.language, r.n, time_str, result_str
+            "| {:^12} | {:^8} | {:^15} | {:^15} | {:^15} | {:^15} | {:^25} | {:^12} | {:^18} |\n",
+            r.method, r.language, r.n, time_str, median_str, std_dev_str, ci_str, result_str, r.transport
         ));
     }
 
-    output.push_str(&format!("{:â”€^80}\n", ""));
+    output.push_str(&format!("{:â”€^150}\n", ""));
 
     // Calculate speedups
     output.push_str("\nðŸ“Š Speedup Analysis (Rust vs Go):\n");
@@ -0,0 +1,202 @@
+//! Outlier-robust statistics for cross-language timing comparisons
+//!
+//! `compare_implementations` used to just sum durations and divide by the
+//! iteration count, so a single scheduler hiccup could poison the reported
+//! average and the speedup factors derived from it. This collects every
+//! per-iteration sample, discards outliers via the median-absolute-deviation
+//! rule, and reports a 95% confidence interval via bootstrap resampling of
+//! the kept samples. Implemented locally (rather than depending on
+//! `fib_profiler::stats`, which solves the same problem) so this crate
+//! doesn't need a cross-dependency on a profiling tool just for a handful of
+//! summary numbers.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+
+/// Number of bootstrap resamples used to estimate the 95% confidence interval
+const BOOTSTRAP_RESAMPLES: usize = 1000;
+
+/// Samples farther than this many scaled MADs from the median are discarded
+const MAD_OUTLIER_THRESHOLD: f64 = 3.0;
+
+/// Scales the median absolute deviation to be a consistent estimator of the
+/// standard deviation under a normal distribution
+const MAD_TO_STDDEV: f64 = 1.4826;
+
+/// Outlier-robust summary of a batch of nanosecond timing samples
+#[derive(Debug, Clone, Copy)]
+pub struct RobustStats {
+    pub mean_ns: f64,
+    pub median_ns: f64,
+    pub std_dev_ns: f64,
+    pub ci_low_ns: f64,
+    pub ci_high_ns: f64,
+    pub kept: usize,
+    pub discarded: usize,
+}
+
+/// Drop median-absolute-deviation outliers from `samples_ns`, then report
+/// the mean/median/stddev of what's left plus a 95% bootstrap confidence
+/// interval around the mean.
+pub fn summarize(samples_ns: &[f64]) -> RobustStats {
+    if samples_ns.is_empty() {
+        return RobustStats {
+            mean_ns: 0.0,
+            median_ns: 0.0,
+            std_dev_ns: 0.0,
+            ci_low_ns: 0.0,
+            ci_high_ns: 0.0,
+            kept: 0,
+            discarded: 0,
+        };
+    }
+
+    let median = median_of(samples_ns);
+    let abs_devs: Vec<f64> = samples_ns.iter().map(|&v| (v - median).abs()).collect();
+    let mad = median_of(&abs_devs) * MAD_TO_STDDEV;
+
+    let kept: Vec<f64> = if mad == 0.0 {
+        samples_ns.to_vec()
+    } else {
+        samples_ns
+            .iter()
+            .copied()
+            .filter(|&v| (v - median).abs() <= MAD_OUTLIER_THRESHOLD * mad)
+            .collect()
+    };
+    let discarded = samples_ns.len() - kept.len();
+
+    let mean_ns = kept.iter().sum::<f64>() / kept.len() as f64;
+    let variance = kept.iter().map(|&v| (v - mean_ns).powi(2)).sum::<f64>() / kept.len() as f64;
+    let (ci_low_ns, ci_high_ns) = bootstrap_mean_ci(&kept, BOOTSTRAP_RESAMPLES);
+
+    RobustStats {
+        mean_ns,
+        median_ns: median_of(&kept),
+        std_dev_ns: variance.sqrt(),
+        ci_low_ns,
+        ci_high_ns,
+        kept: kept.len(),
+        discarded,
+    }
+}
+
+fn median_of(values: &[f64]) -> f64 {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let mid = sorted.len() / 2;
+    if sorted.len() % 2 == 0 {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    }
+}
+
+/// Resample `samples` with replacement `resamples` times, computing each
+/// resample's mean, and return the 2.5th/97.5th percentile of those means as
+/// a 95% confidence interval.
+fn bootstrap_mean_ci(samples: &[f64], resamples: usize) -> (f64, f64) {
+    if samples.len() <= 1 {
+        return (samples.first().copied().unwrap_or(0.0), samples.first().copied().unwrap_or(0.0));
+    }
+
+    let mut rng = SplitMix64::new(random_seed());
+    let mut means: Vec<f64> = (0..resamples)
+        .map(|_| {
+            (0..samples.len())
+                .map(|_| samples[rng.next_index(samples.len())])
+                .sum::<f64>()
+                / samples.len() as f64
+        })
+        .collect();
+    means.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    (percentile(&means, 0.025), percentile(&means, 0.975))
+}
+
+/// Linear-interpolated percentile of an already-sorted slice
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.len() == 1 {
+        return sorted[0];
+    }
+    let rank = p * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    if lower == upper {
+        sorted[lower]
+    } else {
+        let frac = rank - lower as f64;
+        sorted[lower] + (sorted[upper] - sorted[lower]) * frac
+    }
+}
+
+/// OS-randomized seed for the bootstrap resampler, sourced from `std`'s
+/// `HashMap` randomization rather than an external `rand` dependency.
+fn random_seed() -> u64 {
+    RandomState::new().build_hasher().finish()
+}
+
+/// Minimal SplitMix64 PRNG, good enough to seed bootstrap resampling
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    fn next_index(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summarize_constant_samples() {
+        let samples = vec![100.0; 50];
+        let stats = summarize(&samples);
+        assert_eq!(stats.kept, 50);
+        assert_eq!(stats.discarded, 0);
+        assert_eq!(stats.mean_ns, 100.0);
+        assert_eq!(stats.median_ns, 100.0);
+        assert_eq!(stats.std_dev_ns, 0.0);
+        assert_eq!(stats.ci_low_ns, 100.0);
+        assert_eq!(stats.ci_high_ns, 100.0);
+    }
+
+    #[test]
+    fn test_summarize_discards_mad_outlier() {
+        let mut samples: Vec<f64> = (0..30).map(|i| 95.0 + (i % 10) as f64).collect();
+        samples.push(100_000.0);
+        let stats = summarize(&samples);
+        assert_eq!(stats.discarded, 1);
+        assert_eq!(stats.kept, 30);
+    }
+
+    #[test]
+    fn test_bootstrap_ci_contains_mean_for_varied_samples() {
+        let samples: Vec<f64> = (0..50).map(|i| 100.0 + (i % 5) as f64).collect();
+        let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+        let (low, high) = bootstrap_mean_ci(&samples, 1000);
+        assert!(low <= mean && mean <= high);
+    }
+
+    #[test]
+    fn test_summarize_empty() {
+        let stats = summarize(&[]);
+        assert_eq!(stats.kept, 0);
+        assert_eq!(stats.mean_ns, 0.0);
+    }
+}
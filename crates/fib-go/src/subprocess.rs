@@ -0,0 +1,145 @@
+//! Subprocess transport for cross-language comparison
+//!
+//! An alternative to the `ffi` module's CGO bridge: instead of linking a
+//! compiled Go archive, this spawns a real `go run` (or a Python fallback)
+//! child process and speaks a line protocol over its stdin/stdout, so a
+//! comparison can run without CGO or a C toolchain at all. Modeled on
+//! `fib_profiler::external`'s spawn-once-per-run approach, but here one line
+//! carries the method id, `n`, and an iteration count together so a single
+//! child can serve every method in a comparison run instead of one child per
+//! method.
+
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::Path;
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+
+/// Default path (relative to the workspace root) to the companion Go
+/// program speaking the subprocess protocol
+pub const DEFAULT_GO_SCRIPT: &str = "crates/fib-go/go/subprocess/main.go";
+
+/// Default path (relative to the workspace root) to the companion Python
+/// program speaking the same protocol
+pub const DEFAULT_PYTHON_SCRIPT: &str = "crates/fib-go/scripts/fib_subprocess.py";
+
+/// A running child process speaking the method/n/iterations line protocol
+pub struct SubprocessRunner {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+    transport_label: &'static str,
+}
+
+impl SubprocessRunner {
+    /// Spawn `go run <script>`, wiring up stdin/stdout for the line protocol
+    pub fn spawn_go(script: &Path) -> io::Result<Self> {
+        Self::spawn(Command::new("go").arg("run").arg(script), "Subprocess (go run)")
+    }
+
+    /// Spawn `python3 <script>` as a fallback when Go isn't available
+    pub fn spawn_python(script: &Path) -> io::Result<Self> {
+        Self::spawn(Command::new("python3").arg(script), "Subprocess (python3)")
+    }
+
+    fn spawn(command: &mut Command, transport_label: &'static str) -> io::Result<Self> {
+        let mut child = command.stdin(Stdio::piped()).stdout(Stdio::piped()).spawn()?;
+
+        let stdin = child.stdin.take().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::BrokenPipe,
+                "child did not provide a stdin pipe",
+            )
+        })?;
+        let stdout = child.stdout.take().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::BrokenPipe,
+                "child did not provide a stdout pipe",
+            )
+        })?;
+
+        Ok(Self {
+            child,
+            stdin,
+            stdout: BufReader::new(stdout),
+            transport_label,
+        })
+    }
+
+    /// Which concrete transport this runner ended up using, e.g.
+    /// `"Subprocess (go run)"` or `"Subprocess (python3)"`
+    pub fn transport_label(&self) -> &'static str {
+        self.transport_label
+    }
+
+    /// Ask the child to run `method` at `n` for `iterations` rounds, returning
+    /// the elapsed nanoseconds (measured inside the child) and the resulting
+    /// F(n), so callers can sanity-check correctness alongside timing.
+    pub fn run_batch(&mut self, method: &str, n: u64, iterations: u32) -> io::Result<(f64, u64)> {
+        writeln!(self.stdin, "{} {} {}", method, n, iterations)?;
+        self.stdin.flush()?;
+
+        let mut line = String::new();
+        if self.stdout.read_line(&mut line)? == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "child closed stdout before replying",
+            ));
+        }
+
+        let mut fields = line.split_whitespace();
+        let elapsed_ns: f64 = fields
+            .next()
+            .and_then(|f| f.parse().ok())
+            .ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, "missing elapsed_ns field in reply")
+            })?;
+        let result: u64 = fields.next().and_then(|f| f.parse().ok()).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "missing result field in reply")
+        })?;
+
+        Ok((elapsed_ns, result))
+    }
+}
+
+impl Drop for SubprocessRunner {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// Try `go run` on [`DEFAULT_GO_SCRIPT`] first, falling back to `python3` on
+/// [`DEFAULT_PYTHON_SCRIPT`] if the `go` command cannot be spawned, and
+/// returning `None` if neither command can be spawned. A script that is
+/// missing or exits early is not detected here; it surfaces as an error from
+/// the first [`SubprocessRunner::run_batch`].
+pub fn spawn_default() -> Option<SubprocessRunner> {
+    SubprocessRunner::spawn_go(Path::new(DEFAULT_GO_SCRIPT))
+        .or_else(|_| SubprocessRunner::spawn_python(Path::new(DEFAULT_PYTHON_SCRIPT)))
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `go`/`python3` may or may not be installed in the environment running
+    // these tests, so a missing script can fail either at spawn (interpreter
+    // absent) or at the first `run_batch` (interpreter present, but exits
+    // immediately because the script doesn't exist) — both count as failure.
+
+    #[test]
+    fn test_missing_go_script_fails_at_spawn_or_first_batch() {
+        match SubprocessRunner::spawn_go(Path::new("/no/such/fib_subprocess.go")) {
+            Ok(mut runner) => assert!(runner.run_batch("iterative", 10, 1).is_err()),
+            Err(_) => {}
+        }
+    }
+
+    #[test]
+    fn test_missing_python_script_fails_at_spawn_or_first_batch() {
+        match SubprocessRunner::spawn_python(Path::new("/no/such/fib_subprocess.py")) {
+            Ok(mut runner) => assert!(runner.run_batch("iterative", 10, 1).is_err()),
+            Err(_) => {}
+        }
+    }
+}
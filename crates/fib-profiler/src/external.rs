@@ -0,0 +1,119 @@
+//! External-process benchmark protocol
+//!
+//! A line-based protocol, modeled on Criterion's external-process
+//! measurements, for timing a Fibonacci implementation that lives in another
+//! language/process: the parent spawns the child once (passing the
+//! Fibonacci index `n` as its sole argv), then repeatedly writes an
+//! iteration count as a line to the child's stdin; the child runs its own
+//! Fibonacci implementation that many times with its own internal timer and
+//! writes the elapsed nanoseconds back as a line on stdout. Doing the timing
+//! inside the child avoids attributing process-spawn overhead to every
+//! sample, unlike the one-shot-per-call harness in `fib-cli`'s `external`
+//! command.
+//!
+//! The per-round elapsed time divided by its iteration count becomes one
+//! sample in the returned `Vec<f64>`, which can feed straight into
+//! [`crate::stats::bootstrap_mean_ci`] and [`crate::stats::classify_outliers`]
+//! the same way an in-process `stats::profile` run would.
+
+use std::io::{self, BufRead, BufReader, Write};
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+
+/// A running child process speaking the iteration-count/elapsed-ns protocol
+pub struct ExternalProcess {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+}
+
+impl ExternalProcess {
+    /// Spawn `command n`, wiring up stdin/stdout for the line protocol
+    pub fn spawn(command: &str, n: u64) -> io::Result<Self> {
+        let mut child = Command::new(command)
+            .arg(n.to_string())
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()?;
+
+        let stdin = child.stdin.take().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::BrokenPipe, "child did not provide a stdin pipe")
+        })?;
+        let stdout = child.stdout.take().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::BrokenPipe, "child did not provide a stdout pipe")
+        })?;
+
+        Ok(Self {
+            child,
+            stdin,
+            stdout: BufReader::new(stdout),
+        })
+    }
+
+    /// Ask the child to run its Fibonacci implementation `iterations` times
+    /// and return the total elapsed nanoseconds it measured internally.
+    pub fn run_batch(&mut self, iterations: u32) -> io::Result<f64> {
+        writeln!(self.stdin, "{}", iterations)?;
+        self.stdin.flush()?;
+
+        let mut line = String::new();
+        if self.stdout.read_line(&mut line)? == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "child closed stdout before replying",
+            ));
+        }
+
+        line.trim()
+            .parse::<f64>()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+impl Drop for ExternalProcess {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// Warm up `command` for `warmup_iterations`, then collect `samples` timed
+/// rounds of `batch_size` iterations each, returning the per-iteration
+/// nanoseconds observed in every round.
+pub fn benchmark_samples(
+    command: &str,
+    n: u64,
+    warmup_iterations: u32,
+    batch_size: u32,
+    samples: usize,
+) -> io::Result<Vec<f64>> {
+    let mut proc = ExternalProcess::spawn(command, n)?;
+
+    if warmup_iterations > 0 {
+        proc.run_batch(warmup_iterations)?;
+    }
+
+    let mut per_iter_ns = Vec::with_capacity(samples);
+    for _ in 0..samples {
+        let total_ns = proc.run_batch(batch_size)?;
+        per_iter_ns.push(total_ns / batch_size as f64);
+    }
+
+    Ok(per_iter_ns)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spawn_missing_executable_errors() {
+        let result = ExternalProcess::spawn("/no/such/fib-external-binary", 10);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_benchmark_samples_missing_executable_errors() {
+        let result = benchmark_samples("/no/such/fib-external-binary", 10, 5, 10, 5);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,9 @@
+//! Fibonacci Profiler library
+//!
+//! Houses the statistical machinery shared between the `fib-profiler`
+//! binary and `fib-cli`'s `bench` command, so both speak the same
+//! confidence-interval/outlier vocabulary instead of each rolling their own.
+
+pub mod external;
+pub mod measurement;
+pub mod stats;
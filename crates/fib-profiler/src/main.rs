@@ -4,7 +4,8 @@
 
 use fib_core::allocator::TrackingAllocator;
 use fib_core::{iterative, matrix};
-use std::time::{Duration, Instant};
+use fib_profiler::measurement::{self, AllocationMeasurement};
+use fib_profiler::stats::{self, BootstrapReport};
 
 #[global_allocator]
 static ALLOCATOR: TrackingAllocator = TrackingAllocator::new();
@@ -29,17 +30,14 @@ fn profile_iterative() {
     let test_values = [10, 100, 1000, 10000, 100000];
 
     for n in test_values {
-        let iterations = 1000;
-        let mut total_time = Duration::ZERO;
-
-        for _ in 0..iterations {
-            let start = Instant::now();
-            let _ = iterative::fib_iterative(n);
-            total_time += start.elapsed();
-        }
-
-        let avg_time = total_time / iterations;
-        println!("  n = {:6}: avg {:?}", n, avg_time);
+        let report = stats::profile_adaptive(
+            stats::DEFAULT_WARMUP_TIME,
+            stats::DEFAULT_MEASURE_TIME,
+            stats::DEFAULT_MIN_SAMPLES,
+            stats::DEFAULT_BOOTSTRAP_RESAMPLES,
+            || stats::black_box_call(n, iterative::fib_iterative),
+        );
+        println!("  n = {:6}: {}", n, format_report(&report));
     }
     println!();
 }
@@ -51,21 +49,31 @@ fn profile_matrix() {
     let test_values = [10, 100, 1000, 10000, 100000];
 
     for n in test_values {
-        let iterations = 1000;
-        let mut total_time = Duration::ZERO;
-
-        for _ in 0..iterations {
-            let start = Instant::now();
-            let _ = matrix::fib_matrix_fast(n);
-            total_time += start.elapsed();
-        }
-
-        let avg_time = total_time / iterations;
-        println!("  n = {:6}: avg {:?}", n, avg_time);
+        let report = stats::profile_adaptive(
+            stats::DEFAULT_WARMUP_TIME,
+            stats::DEFAULT_MEASURE_TIME,
+            stats::DEFAULT_MIN_SAMPLES,
+            stats::DEFAULT_BOOTSTRAP_RESAMPLES,
+            || stats::black_box_call(n, matrix::fib_matrix_fast),
+        );
+        println!("  n = {:6}: {}", n, format_report(&report));
     }
     println!();
 }
 
+/// Render a `BootstrapReport` the way every `profile_*` function wants it:
+/// the point estimate and 95% CI in nanoseconds, plus the outlier counts.
+fn format_report(report: &BootstrapReport) -> String {
+    format!(
+        "{:.1} ns  (95% CI [{:.1}, {:.1}])  outliers: {} mild, {} severe",
+        report.ci.point_estimate_ns,
+        report.ci.lower_ns,
+        report.ci.upper_ns,
+        report.outliers.mild,
+        report.outliers.severe
+    )
+}
+
 fn profile_memory_usage() {
     println!("📊 Memory Analysis");
     println!("──────────────────");
@@ -79,29 +87,21 @@ fn profile_memory_usage() {
     ALLOCATOR.reset();
     let _initial_usage = ALLOCATOR.get_current_usage();
 
-    // Demonstrate recursive memo memory
+    // Demonstrate recursive memo memory, via the pluggable `Measurement`
+    // trait's `AllocationMeasurement` rather than a one-off peak read: the
+    // memo `Vec` is dropped inside the function, so `get_current_usage()`
+    // falls back to ~0 by the time we'd measure it, but the allocator's
+    // high-water mark isn't affected by that subsequent dealloc.
     for n in [100, 1000, 10000] {
-        let before_alloc = ALLOCATOR.get_allocation_count();
-
-        let _result = fib_core::recursive::fib_recursive_memo(n);
-
-        // Note: Vec is deallocated when _result is dropped? No, fib_recursive_memo returns basic type u128.
-        // The implementation of fib_recursive_memo creates a cache internally and drops it.
-        // So we might need to query the maximum usage *during* the call if we could,
-        // but here we are checking the net effect or traffic.
-        // Let's rely on theoretical calculation for now but printed alongside real tracking if possible.
-        // Actually, since the Vec is dropped inside the function, the 'current usage' will return to initial.
-        // To track peak usage, our simple allocator needs peak tracking.
-        // But the plan was just to "show real stats".
-        // Let's show TOTAL allocations made.
-
-        let after_alloc = ALLOCATOR.get_allocation_count();
-        let alloc_count = after_alloc - before_alloc;
+        let alloc_measurement = AllocationMeasurement::new(&ALLOCATOR);
+        let report = measurement::profile_with(&alloc_measurement, 20, 2000, || {
+            fib_core::recursive::fib_recursive_memo(std::hint::black_box(n))
+        });
 
         let theoretical_bytes = (n + 1) * 16; // u128 = 16 bytes
         println!(
-            "  Recursive Memo (n={:<5}): ~{:>6} bytes theoretical. Allocations made: {}",
-            n, theoretical_bytes, alloc_count
+            "  Recursive Memo (n={:<5}): measured peak {:>7.0} {} (95% CI [{:.0}, {:.0}]) (~{:>6} theoretical)",
+            n, report.point_estimate, report.unit_label, report.lower, report.upper, theoretical_bytes
         );
     }
 
@@ -131,38 +131,34 @@ fn profile_scaling() {
     println!("──────────────────────────────────────────");
 
     let test_values = [100, 1000, 10000, 100000];
-    let iterations = 100;
 
     println!(
-        "  {:>10} │ {:>15} │ {:>15} │ {:>10}",
-        "n", "Iterative", "Matrix", "Speedup"
+        "  {:>10} │ {:>20} │ {:>20} │ {:>10}",
+        "n", "Iterative (ns)", "Matrix (ns)", "Speedup"
     );
-    println!("  ───────────┼─────────────────┼─────────────────┼───────────");
+    println!("  ───────────┼──────────────────────┼──────────────────────┼───────────");
 
     for n in test_values {
-        // Time iterative
-        let mut iter_time = Duration::ZERO;
-        for _ in 0..iterations {
-            let start = Instant::now();
-            let _ = iterative::fib_iterative(n);
-            iter_time += start.elapsed();
-        }
-        let iter_avg = iter_time / iterations;
-
-        // Time matrix
-        let mut matrix_time = Duration::ZERO;
-        for _ in 0..iterations {
-            let start = Instant::now();
-            let _ = matrix::fib_matrix_fast(n);
-            matrix_time += start.elapsed();
-        }
-        let matrix_avg = matrix_time / iterations;
+        let iter_report = stats::profile_adaptive(
+            stats::DEFAULT_WARMUP_TIME,
+            stats::DEFAULT_MEASURE_TIME,
+            stats::DEFAULT_MIN_SAMPLES,
+            stats::DEFAULT_BOOTSTRAP_RESAMPLES,
+            || stats::black_box_call(n, iterative::fib_iterative),
+        );
+        let matrix_report = stats::profile_adaptive(
+            stats::DEFAULT_WARMUP_TIME,
+            stats::DEFAULT_MEASURE_TIME,
+            stats::DEFAULT_MIN_SAMPLES,
+            stats::DEFAULT_BOOTSTRAP_RESAMPLES,
+            || stats::black_box_call(n, matrix::fib_matrix_fast),
+        );
 
-        let speedup = iter_avg.as_nanos() as f64 / matrix_avg.as_nanos() as f64;
+        let speedup = iter_report.ci.point_estimate_ns / matrix_report.ci.point_estimate_ns;
 
         println!(
-            "  {:>10} │ {:>15?} │ {:>15?} │ {:>10.2}x",
-            n, iter_avg, matrix_avg, speedup
+            "  {:>10} │ {:>20.1} │ {:>20.1} │ {:>10.2}x",
+            n, iter_report.ci.point_estimate_ns, matrix_report.ci.point_estimate_ns, speedup
         );
     }
     println!();
@@ -180,9 +176,11 @@ fn profile_flamegraph() {
         println!("  Capturing profile for flamegraph...");
         let guard = pprof::ProfilerGuard::new(100).unwrap();
 
-        // Run a heavy computation to profile
+        // Run a heavy computation to profile. black_box on both the input
+        // and the (otherwise-dropped) result keeps the optimizer from
+        // hoisting or eliding this loop entirely.
         for _ in 0..10_000 {
-            let _ = matrix::fib_matrix_fast(10_000);
+            stats::black_box_call(10_000u64, matrix::fib_matrix_fast);
         }
 
         if let Ok(report) = guard.report().build() {
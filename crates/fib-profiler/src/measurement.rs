@@ -0,0 +1,160 @@
+//! Pluggable measurement metric for the profiler
+//!
+//! Every report in [`crate::stats`] is wall-clock time because that's all
+//! the profiler ever measured. This module abstracts *what* gets measured
+//! behind a [`Measurement`] trait — the way Criterion lets a benchmark swap
+//! its measurement — so the same start/sample/end plumbing can report bytes
+//! allocated instead of nanoseconds elapsed, without duplicating the
+//! sampling loop for each metric.
+
+use fib_core::allocator::TrackingAllocator;
+use std::time::Instant;
+
+use crate::stats::OutlierCounts;
+
+/// A pluggable benchmark metric: something that can be started before a call
+/// and ended after it, producing one `f64` sample in its own unit.
+pub trait Measurement {
+    /// Opaque value threaded from `start` to `end`, analogous to Criterion's
+    /// `Intermediate` — an `Instant` for wall-clock time, `()` for a metric
+    /// that reads ambient state instead.
+    type Intermediate;
+
+    /// Called immediately before the timed/measured call
+    fn start(&self) -> Self::Intermediate;
+
+    /// Called immediately after the timed/measured call, turning the value
+    /// `start` produced into this sample's measurement
+    fn end(&self, start: Self::Intermediate) -> f64;
+
+    /// Unit label for display, e.g. `"ns"` or `"bytes"`
+    fn unit_label(&self) -> &'static str;
+}
+
+/// The default metric: wall-clock time via `Instant`, in nanoseconds
+pub struct WallClock;
+
+impl Measurement for WallClock {
+    type Intermediate = Instant;
+
+    fn start(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn end(&self, start: Instant) -> f64 {
+        start.elapsed().as_nanos() as f64
+    }
+
+    fn unit_label(&self) -> &'static str {
+        "ns"
+    }
+}
+
+/// Bytes allocated during a call, attributed via a [`TrackingAllocator`]'s
+/// high-water mark rather than its current-usage counter, so allocate-then-
+/// free patterns (e.g. `fib_recursive_memo`'s memo `Vec`) still get measured
+/// even though nothing is left allocated by the time `end` runs.
+pub struct AllocationMeasurement<'a> {
+    allocator: &'a TrackingAllocator,
+}
+
+impl<'a> AllocationMeasurement<'a> {
+    pub fn new(allocator: &'a TrackingAllocator) -> Self {
+        Self { allocator }
+    }
+}
+
+impl Measurement for AllocationMeasurement<'_> {
+    type Intermediate = ();
+
+    fn start(&self) {
+        // Only the high-water mark is reset per sample; unlike `reset()`,
+        // this leaves the allocator's running allocation count intact so
+        // callers can still read a cumulative total across samples.
+        self.allocator.reset_peak();
+    }
+
+    fn end(&self, _start: ()) -> f64 {
+        self.allocator.get_peak_usage() as f64
+    }
+
+    fn unit_label(&self) -> &'static str {
+        "bytes"
+    }
+}
+
+/// A [`crate::stats::BootstrapReport`] generalized to an arbitrary
+/// [`Measurement`]'s unit instead of assuming nanoseconds.
+#[derive(Debug, Clone)]
+pub struct MeasurementReport {
+    pub samples: usize,
+    pub unit_label: &'static str,
+    pub point_estimate: f64,
+    pub lower: f64,
+    pub upper: f64,
+    pub outliers: OutlierCounts,
+}
+
+/// Run `f` repeatedly under `measurement`, collecting `samples` values
+/// (after a short warmup) and returning a bootstrap confidence interval plus
+/// outlier classification in `measurement`'s own unit.
+pub fn profile_with<M, F, T>(measurement: &M, samples: usize, resamples: usize, mut f: F) -> MeasurementReport
+where
+    M: Measurement,
+    F: FnMut() -> T,
+{
+    const WARMUP_ITERATIONS: usize = 10;
+
+    for _ in 0..WARMUP_ITERATIONS {
+        let start = measurement.start();
+        let result = std::hint::black_box(f());
+        let _ = measurement.end(start);
+        std::hint::black_box(result);
+    }
+
+    let mut values: Vec<f64> = Vec::with_capacity(samples);
+    for _ in 0..samples {
+        let start = measurement.start();
+        let result = f();
+        let value = measurement.end(start);
+        std::hint::black_box(result);
+        values.push(value);
+    }
+
+    let ci = crate::stats::bootstrap_mean_ci(&values, resamples);
+    let outliers = crate::stats::classify_outliers(&values);
+
+    MeasurementReport {
+        samples: values.len(),
+        unit_label: measurement.unit_label(),
+        point_estimate: ci.point_estimate_ns,
+        lower: ci.lower_ns,
+        upper: ci.upper_ns,
+        outliers,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_with_wall_clock_reports_ns_samples() {
+        let report = profile_with(&WallClock, 20, 500, || 1 + 1);
+        assert_eq!(report.samples, 20);
+        assert_eq!(report.unit_label, "ns");
+        assert!(report.point_estimate >= 0.0);
+    }
+
+    #[test]
+    fn test_profile_with_allocation_measurement_reports_bytes() {
+        let allocator = TrackingAllocator::new();
+        let measurement = AllocationMeasurement::new(&allocator);
+        // Nothing allocated by this closure, but the plumbing should still
+        // run and report zero bytes rather than erroring.
+        let report = profile_with(&measurement, 5, 200, || 1 + 1);
+        assert_eq!(report.samples, 5);
+        assert_eq!(report.unit_label, "bytes");
+        assert_eq!(report.point_estimate, 0.0);
+    }
+}
@@ -0,0 +1,410 @@
+//! Bootstrap confidence intervals and outlier classification
+//!
+//! `fib-cli`'s `stats` module already collects timing samples and a single
+//! mild-outlier count; this module adds the heavier machinery the profiler
+//! wants on top of that: a bootstrap-resampled 95% confidence interval
+//! around the mean (the way Criterion reports its point estimate), and
+//! two-tier (mild/severe) Tukey-fence outlier classification.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::{Duration, Instant};
+
+/// Default number of bootstrap resamples drawn to build a confidence interval
+pub const DEFAULT_BOOTSTRAP_RESAMPLES: usize = 100_000;
+
+/// Untimed warmup iterations run before sampling begins
+const WARMUP_ITERATIONS: usize = 10;
+
+/// Default duration spent estimating per-iteration cost before sizing the
+/// measurement phase
+pub const DEFAULT_WARMUP_TIME: Duration = Duration::from_millis(500);
+
+/// Default total wall-clock budget for the measurement phase
+pub const DEFAULT_MEASURE_TIME: Duration = Duration::from_secs(1);
+
+/// Floor on the number of measured samples regardless of the timing budget
+pub const DEFAULT_MIN_SAMPLES: usize = 10;
+
+/// A 95% confidence interval around a point estimate, built by bootstrap
+/// resampling rather than assuming a normal distribution.
+#[derive(Debug, Clone, Copy)]
+pub struct ConfidenceInterval {
+    pub point_estimate_ns: f64,
+    pub lower_ns: f64,
+    pub upper_ns: f64,
+}
+
+/// Counts of samples falling outside the Tukey inner and outer fences
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OutlierCounts {
+    /// Outside [Q1 - 1.5*IQR, Q3 + 1.5*IQR] but inside the severe fences
+    pub mild: usize,
+    /// Outside [Q1 - 3*IQR, Q3 + 3*IQR]
+    pub severe: usize,
+}
+
+impl OutlierCounts {
+    pub fn total(&self) -> usize {
+        self.mild + self.severe
+    }
+}
+
+/// Full statistical report for a batch of timing samples, in nanoseconds
+#[derive(Debug, Clone)]
+pub struct BootstrapReport {
+    pub samples: usize,
+    pub ci: ConfidenceInterval,
+    pub outliers: OutlierCounts,
+}
+
+/// Call `f(n)` with `n` passed through `black_box` on the way in and the
+/// result passed through `black_box` on the way out.
+///
+/// Every measurement loop in this crate times a call whose result is
+/// otherwise dropped immediately, which leaves the optimizer free to hoist
+/// it out of the loop or delete it outright — `black_box` on both sides is
+/// the one shape that reliably defeats that. Use this helper instead of
+/// sprinkling `std::hint::black_box` calls ad hoc at each call site.
+pub fn black_box_call<T, U>(n: T, mut f: impl FnMut(T) -> U) -> U {
+    std::hint::black_box(f(std::hint::black_box(n)))
+}
+
+/// Run `f` repeatedly, collecting `samples` timing measurements (after a
+/// short warmup), and return a bootstrap confidence interval plus outlier
+/// classification in nanoseconds.
+///
+/// `f` is invoked `WARMUP_ITERATIONS + samples` times in total. Its return
+/// value is passed through `black_box` so the optimizer can't elide the call.
+pub fn profile<F, T>(samples: usize, resamples: usize, mut f: F) -> BootstrapReport
+where
+    F: FnMut() -> T,
+{
+    for _ in 0..WARMUP_ITERATIONS {
+        std::hint::black_box(f());
+    }
+
+    let mut durations_ns: Vec<f64> = Vec::with_capacity(samples);
+    for _ in 0..samples {
+        let start = Instant::now();
+        let result = f();
+        let elapsed = start.elapsed();
+        std::hint::black_box(result);
+        durations_ns.push(elapsed.as_nanos() as f64);
+    }
+
+    let ci = bootstrap_mean_ci(&durations_ns, resamples);
+    let outliers = classify_outliers(&durations_ns);
+
+    BootstrapReport {
+        samples: durations_ns.len(),
+        ci,
+        outliers,
+    }
+}
+
+/// Result of [`size_iterations`]: how many measured iterations to run and
+/// the per-iteration cost that sizing was based on.
+#[derive(Debug, Clone, Copy)]
+pub struct AdaptiveSizing {
+    pub iterations: usize,
+    pub est_ns_per_iter: f64,
+    /// `true` if a single call to `f` already exceeded `measure_time`, so
+    /// `iterations` was forced to 1 instead of being computed from a budget
+    pub single_sample_fallback: bool,
+}
+
+/// Estimate `f`'s per-call cost by running it for `warmup` wall-clock time,
+/// then size the measurement phase so it takes roughly `measure_time` in
+/// total, never going below `min_samples`. If even one call to `f` exceeds
+/// `measure_time`, falls back to a single timed sample.
+pub fn size_iterations<F, T>(
+    warmup: Duration,
+    measure_time: Duration,
+    min_samples: usize,
+    mut f: F,
+) -> AdaptiveSizing
+where
+    F: FnMut() -> T,
+{
+    let warmup_start = Instant::now();
+    let mut warmup_iters: u64 = 0;
+    while warmup_start.elapsed() < warmup {
+        std::hint::black_box(f());
+        warmup_iters += 1;
+    }
+
+    if warmup_iters == 0 {
+        let start = Instant::now();
+        std::hint::black_box(f());
+        let elapsed_ns = start.elapsed().as_nanos() as f64;
+        return AdaptiveSizing {
+            iterations: 1,
+            est_ns_per_iter: elapsed_ns,
+            single_sample_fallback: elapsed_ns > measure_time.as_nanos() as f64,
+        };
+    }
+
+    let est_ns_per_iter = warmup_start.elapsed().as_nanos() as f64 / warmup_iters as f64;
+    if est_ns_per_iter > measure_time.as_nanos() as f64 {
+        return AdaptiveSizing {
+            iterations: 1,
+            est_ns_per_iter,
+            single_sample_fallback: true,
+        };
+    }
+    let target_iters = (measure_time.as_nanos() as f64 / est_ns_per_iter).round() as usize;
+
+    AdaptiveSizing {
+        iterations: target_iters.max(min_samples).max(1),
+        est_ns_per_iter,
+        single_sample_fallback: false,
+    }
+}
+
+/// Warm up and auto-size `f`'s measurement phase with [`size_iterations`],
+/// then run it and return the same bootstrap CI + outlier report as
+/// [`profile`] — just without a hard-coded iteration count.
+pub fn profile_adaptive<F, T>(
+    warmup: Duration,
+    measure_time: Duration,
+    min_samples: usize,
+    resamples: usize,
+    mut f: F,
+) -> BootstrapReport
+where
+    F: FnMut() -> T,
+{
+    let sizing = size_iterations(warmup, measure_time, min_samples, &mut f);
+
+    if sizing.single_sample_fallback {
+        return BootstrapReport {
+            samples: 1,
+            ci: ConfidenceInterval {
+                point_estimate_ns: sizing.est_ns_per_iter,
+                lower_ns: sizing.est_ns_per_iter,
+                upper_ns: sizing.est_ns_per_iter,
+            },
+            outliers: OutlierCounts::default(),
+        };
+    }
+
+    profile(sizing.iterations, resamples, f)
+}
+
+/// Compute a 95% confidence interval around the mean of `samples` by
+/// bootstrap resampling: draw `resamples` samples of the same length with
+/// replacement, compute the mean of each, and report the 2.5th/97.5th
+/// percentiles of the resampled means alongside the point estimate.
+pub fn bootstrap_mean_ci(samples: &[f64], resamples: usize) -> ConfidenceInterval {
+    let n = samples.len();
+    if n == 0 {
+        return ConfidenceInterval {
+            point_estimate_ns: 0.0,
+            lower_ns: 0.0,
+            upper_ns: 0.0,
+        };
+    }
+
+    let point_estimate_ns = samples.iter().sum::<f64>() / n as f64;
+
+    let mut rng = SplitMix64::new(random_seed());
+    let mut resampled_means: Vec<f64> = Vec::with_capacity(resamples);
+    for _ in 0..resamples {
+        let mut sum = 0.0;
+        for _ in 0..n {
+            sum += samples[rng.next_index(n)];
+        }
+        resampled_means.push(sum / n as f64);
+    }
+    resampled_means.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    ConfidenceInterval {
+        point_estimate_ns,
+        lower_ns: percentile(&resampled_means, 0.025),
+        upper_ns: percentile(&resampled_means, 0.975),
+    }
+}
+
+/// Classify samples with Tukey fences: outside [Q1 - 1.5*IQR, Q3 + 1.5*IQR]
+/// is a mild outlier, outside [Q1 - 3*IQR, Q3 + 3*IQR] is severe.
+pub fn classify_outliers(samples: &[f64]) -> OutlierCounts {
+    if samples.len() < 4 {
+        return OutlierCounts::default();
+    }
+
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let q1 = percentile(&sorted, 0.25);
+    let q3 = percentile(&sorted, 0.75);
+    let iqr = q3 - q1;
+
+    let mild_lower = q1 - 1.5 * iqr;
+    let mild_upper = q3 + 1.5 * iqr;
+    let severe_lower = q1 - 3.0 * iqr;
+    let severe_upper = q3 + 3.0 * iqr;
+
+    let mut outliers = OutlierCounts::default();
+    for &v in &sorted {
+        if v < severe_lower || v > severe_upper {
+            outliers.severe += 1;
+        } else if v < mild_lower || v > mild_upper {
+            outliers.mild += 1;
+        }
+    }
+
+    outliers
+}
+
+/// Linear-interpolated percentile of an already-sorted slice
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.len() == 1 {
+        return sorted[0];
+    }
+
+    let rank = p * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    if lower == upper {
+        sorted[lower]
+    } else {
+        let frac = rank - lower as f64;
+        sorted[lower] + (sorted[upper] - sorted[lower]) * frac
+    }
+}
+
+/// A seed drawn from the OS's randomness source via the hasher `std` already
+/// uses to protect `HashMap` from collision attacks — avoids pulling in a
+/// dedicated `rand` dependency just to seed a bootstrap resampler.
+fn random_seed() -> u64 {
+    RandomState::new().build_hasher().finish()
+}
+
+/// Small, fast, non-cryptographic PRNG (SplitMix64) used to pick resampling
+/// indices; we only need speed and a reasonably uniform spread, not security.
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform index in `[0, bound)`
+    fn next_index(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bootstrap_mean_ci_constant_samples() {
+        let samples = vec![100.0; 50];
+        let ci = bootstrap_mean_ci(&samples, 2000);
+        assert_eq!(ci.point_estimate_ns, 100.0);
+        assert_eq!(ci.lower_ns, 100.0);
+        assert_eq!(ci.upper_ns, 100.0);
+    }
+
+    #[test]
+    fn test_bootstrap_mean_ci_brackets_point_estimate() {
+        let samples: Vec<f64> = (0..100).map(|i| i as f64).collect();
+        let ci = bootstrap_mean_ci(&samples, 5000);
+        assert!(ci.lower_ns <= ci.point_estimate_ns);
+        assert!(ci.point_estimate_ns <= ci.upper_ns);
+    }
+
+    #[test]
+    fn test_bootstrap_mean_ci_empty() {
+        let ci = bootstrap_mean_ci(&[], 1000);
+        assert_eq!(ci.point_estimate_ns, 0.0);
+        assert_eq!(ci.lower_ns, 0.0);
+        assert_eq!(ci.upper_ns, 0.0);
+    }
+
+    #[test]
+    fn test_classify_outliers_none() {
+        let samples = vec![100.0; 30];
+        let outliers = classify_outliers(&samples);
+        assert_eq!(outliers.mild, 0);
+        assert_eq!(outliers.severe, 0);
+    }
+
+    #[test]
+    fn test_classify_outliers_mild_and_severe() {
+        // Spread base samples so Q1/Q3 aren't degenerate, giving a real gap
+        // between the mild fence (~111) and the severe fence (~119).
+        let mut samples: Vec<f64> = (95..=105).map(|v| v as f64).collect();
+        samples.extend(95..=105);
+        samples.push(115.0); // mild: outside 1.5*IQR but inside 3*IQR
+        samples.push(100_000.0); // severe: way outside 3*IQR
+        let outliers = classify_outliers(&samples);
+        assert_eq!(outliers.mild, 1);
+        assert_eq!(outliers.severe, 1);
+        assert_eq!(outliers.total(), 2);
+    }
+
+    #[test]
+    fn test_black_box_call_returns_f_applied_to_n() {
+        assert_eq!(black_box_call(21, |n| n * 2), 42);
+    }
+
+    #[test]
+    fn test_profile_collects_requested_sample_count() {
+        let report = profile(20, 500, || 1 + 1);
+        assert_eq!(report.samples, 20);
+        assert!(report.ci.point_estimate_ns >= 0.0);
+    }
+
+    #[test]
+    fn test_size_iterations_respects_min_samples_for_fast_work() {
+        // A near-instant closure gets a budget-sized iteration count, which
+        // is never below `min_samples` and never takes the single-sample path.
+        let sizing = size_iterations(
+            Duration::from_millis(10),
+            Duration::from_millis(1),
+            50,
+            || 1 + 1,
+        );
+        assert!(!sizing.single_sample_fallback);
+        assert!(sizing.iterations >= 50);
+    }
+
+    #[test]
+    fn test_size_iterations_single_sample_fallback_for_slow_work() {
+        let sizing = size_iterations(
+            Duration::from_millis(1),
+            Duration::from_millis(1),
+            10,
+            || std::thread::sleep(Duration::from_millis(20)),
+        );
+        assert!(sizing.single_sample_fallback);
+        assert_eq!(sizing.iterations, 1);
+    }
+
+    #[test]
+    fn test_profile_adaptive_produces_a_report() {
+        let report = profile_adaptive(
+            Duration::from_millis(10),
+            Duration::from_millis(10),
+            10,
+            500,
+            || 1 + 1,
+        );
+        assert!(report.samples >= 1);
+        assert!(report.ci.point_estimate_ns >= 0.0);
+    }
+}
@@ -1,11 +1,20 @@
 use crate::data_parser::BenchmarkData;
-use plotly::common::{Mode, Title};
+use plotly::common::{ErrorData, ErrorType, Mode, Title};
 use plotly::layout::{Axis, AxisType, Layout};
 use plotly::{Plot, Scatter};
 use std::path::Path;
 
 use plotly::ImageFormat;
 
+/// Collect a per-point stddev series for error bars, or `None` if any point
+/// is missing it (e.g. data generated before `stddev_ns` existed).
+fn collect_stddevs<T>(
+    points: &[crate::data_parser::ComplexityPoint],
+    field: impl Fn(&crate::data_parser::ComplexityPoint) -> Option<T>,
+) -> Option<Vec<T>> {
+    points.iter().map(field).collect()
+}
+
 pub fn generate_charts(data: &BenchmarkData, output_dir: &str) {
     let dir = Path::new(output_dir);
     if let Err(e) = std::fs::create_dir_all(dir) {
@@ -20,14 +29,23 @@ pub fn generate_charts(data: &BenchmarkData, output_dir: &str) {
     let iter_times: Vec<u128> = data.complexity.iter().map(|p| p.iterative_ns).collect();
     let matrix_times: Vec<u128> = data.complexity.iter().map(|p| p.matrix_ns).collect();
 
-    let trace1 = Scatter::new(n_values.clone(), iter_times)
+    let mut trace1 = Scatter::new(n_values.clone(), iter_times)
         .name("Iterative")
         .mode(Mode::LinesMarkers);
 
-    let trace2 = Scatter::new(n_values, matrix_times)
+    let mut trace2 = Scatter::new(n_values, matrix_times)
         .name("Matrix Exponentiation")
         .mode(Mode::LinesMarkers);
 
+    // Only draw error bars when every point in the series carries a stddev —
+    // older `complexity_comparison.json` files predate these fields.
+    if let Some(errors) = collect_stddevs(&data.complexity, |p| p.iterative_stddev_ns) {
+        trace1 = trace1.error_y(ErrorData::new(ErrorType::Data).array(errors));
+    }
+    if let Some(errors) = collect_stddevs(&data.complexity, |p| p.matrix_stddev_ns) {
+        trace2 = trace2.error_y(ErrorData::new(ErrorType::Data).array(errors));
+    }
+
     plot.add_trace(trace1);
     plot.add_trace(trace2);
 
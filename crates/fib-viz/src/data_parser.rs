@@ -7,6 +7,16 @@ pub struct ComplexityPoint {
     pub n: u64,
     pub iterative_ns: u128,
     pub matrix_ns: u128,
+    /// Sample standard deviation of `iterative_ns`, in nanoseconds.
+    /// `None` for data generated before this field existed.
+    #[serde(default)]
+    pub iterative_stddev_ns: Option<f64>,
+    /// Sample standard deviation of `matrix_ns`, in nanoseconds.
+    #[serde(default)]
+    pub matrix_stddev_ns: Option<f64>,
+    /// Number of timed samples each point was computed from.
+    #[serde(default)]
+    pub sample_size: Option<usize>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
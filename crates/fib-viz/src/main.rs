@@ -37,32 +37,31 @@ fn generate_data() -> std::io::Result<()> {
     Ok(())
 }
 
+/// Number of timed samples collected per `(method, n)` point, after warmup.
+///
+/// Mirrors the `BenchmarkId::new(method_name, n)` sweep style used by the
+/// Criterion harness in `fib-core/benches/fib_benchmarks.rs`, but runs inline
+/// so the statistics can be written straight into `complexity_comparison.json`.
+const COMPLEXITY_SAMPLES: usize = 200;
+
 fn generate_complexity_data() -> std::io::Result<()> {
     println!("📈 Generating complexity comparison data...");
 
     let mut data = Vec::new();
 
     for n in (10..=180).step_by(10) {
-        let iterations = 100;
-
-        // Time iterative
-        let start = std::time::Instant::now();
-        for _ in 0..iterations {
-            let _ = iterative::fib_iterative(n);
-        }
-        let iter_ns = start.elapsed().as_nanos() / iterations as u128;
-
-        // Time matrix
-        let start = std::time::Instant::now();
-        for _ in 0..iterations {
-            let _ = matrix::fib_matrix_fast(n);
-        }
-        let matrix_ns = start.elapsed().as_nanos() / iterations as u128;
+        let iter_stats =
+            sample_ns(COMPLEXITY_SAMPLES, || iterative::fib_iterative(std::hint::black_box(n)));
+        let matrix_stats =
+            sample_ns(COMPLEXITY_SAMPLES, || matrix::fib_matrix_fast(std::hint::black_box(n)));
 
         data.push(ComplexityPoint {
             n,
-            iterative_ns: iter_ns,
-            matrix_ns,
+            iterative_ns: iter_stats.mean_ns as u128,
+            matrix_ns: matrix_stats.mean_ns as u128,
+            iterative_stddev_ns: Some(iter_stats.stddev_ns),
+            matrix_stddev_ns: Some(matrix_stats.stddev_ns),
+            sample_size: Some(COMPLEXITY_SAMPLES),
         });
     }
 
@@ -72,6 +71,46 @@ fn generate_complexity_data() -> std::io::Result<()> {
     Ok(())
 }
 
+/// Mean/stddev of a timed sample, in nanoseconds
+struct TimingStats {
+    mean_ns: f64,
+    stddev_ns: f64,
+}
+
+/// Time `f` `samples` times (after a short warmup) and summarize the result.
+///
+/// This is a lightweight stand-in for a full Criterion run — good enough to
+/// give `complexity_chart` real error bars without pulling the `criterion`
+/// dependency (and its `harness = false` binary target) into the report
+/// generator itself.
+fn sample_ns<F, T>(samples: usize, mut f: F) -> TimingStats
+where
+    F: FnMut() -> T,
+{
+    for _ in 0..10 {
+        std::hint::black_box(f());
+    }
+
+    let mut durations_ns = Vec::with_capacity(samples);
+    for _ in 0..samples {
+        let start = std::time::Instant::now();
+        std::hint::black_box(f());
+        durations_ns.push(start.elapsed().as_nanos() as f64);
+    }
+
+    let mean_ns = durations_ns.iter().sum::<f64>() / durations_ns.len() as f64;
+    let variance = durations_ns
+        .iter()
+        .map(|d| (d - mean_ns).powi(2))
+        .sum::<f64>()
+        / durations_ns.len() as f64;
+
+    TimingStats {
+        mean_ns,
+        stddev_ns: variance.sqrt(),
+    }
+}
+
 fn generate_accuracy_data() -> std::io::Result<()> {
     println!("📈 Generating Binet accuracy data...");
 